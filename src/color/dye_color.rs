@@ -0,0 +1,138 @@
+// src/color/dye_color.rs
+
+#![allow(dead_code)]
+
+use crate::color::Color;
+
+/// The 16 Minecraft dye colors, in their wool/metadata order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(u8)]
+pub enum DyeColor {
+    White,
+    Orange,
+    Magenta,
+    LightBlue,
+    Yellow,
+    Lime,
+    Pink,
+    Gray,
+    LightGray,
+    Cyan,
+    Purple,
+    Blue,
+    Brown,
+    Green,
+    Red,
+    Black,
+}
+
+impl DyeColor {
+    pub const ALL: [DyeColor; 16] = [
+        DyeColor::White,
+        DyeColor::Orange,
+        DyeColor::Magenta,
+        DyeColor::LightBlue,
+        DyeColor::Yellow,
+        DyeColor::Lime,
+        DyeColor::Pink,
+        DyeColor::Gray,
+        DyeColor::LightGray,
+        DyeColor::Cyan,
+        DyeColor::Purple,
+        DyeColor::Blue,
+        DyeColor::Brown,
+        DyeColor::Green,
+        DyeColor::Red,
+        DyeColor::Black,
+    ];
+
+    /// Wool and carpet metadata value (0 = white ... 15 = black). Dye items count the other way;
+    /// see `get_dye_item_meta`.
+    pub const fn get_meta(&self) -> u8 {
+        match self {
+            DyeColor::White => 0,
+            DyeColor::Orange => 1,
+            DyeColor::Magenta => 2,
+            DyeColor::LightBlue => 3,
+            DyeColor::Yellow => 4,
+            DyeColor::Lime => 5,
+            DyeColor::Pink => 6,
+            DyeColor::Gray => 7,
+            DyeColor::LightGray => 8,
+            DyeColor::Cyan => 9,
+            DyeColor::Purple => 10,
+            DyeColor::Blue => 11,
+            DyeColor::Brown => 12,
+            DyeColor::Green => 13,
+            DyeColor::Red => 14,
+            DyeColor::Black => 15,
+        }
+    }
+
+    pub const fn from_meta(meta: u8) -> Option<DyeColor> {
+        if (meta as usize) < DyeColor::ALL.len() {
+            Some(DyeColor::ALL[meta as usize])
+        } else {
+            None
+        }
+    }
+
+    /// Legacy dye item metadata value (0 = black ... 15 = white), the reverse of `get_meta`.
+    pub const fn get_dye_item_meta(&self) -> u8 {
+        15 - self.get_meta()
+    }
+
+    pub const fn from_dye_item_meta(meta: u8) -> Option<DyeColor> {
+        if meta < 16 {
+            DyeColor::from_meta(15 - meta)
+        } else {
+            None
+        }
+    }
+
+    pub const fn get_color(&self) -> Color {
+        match self {
+            DyeColor::White => Color::WHITE,
+            DyeColor::Orange => Color::ORANGE,
+            DyeColor::Magenta => Color::MAGENTA,
+            DyeColor::LightBlue => Color::LIGHT_BLUE,
+            DyeColor::Yellow => Color::YELLOW,
+            DyeColor::Lime => Color::LIME,
+            DyeColor::Pink => Color::PINK,
+            DyeColor::Gray => Color::GRAY,
+            DyeColor::LightGray => Color::LIGHT_GRAY,
+            DyeColor::Cyan => Color::CYAN,
+            DyeColor::Purple => Color::PURPLE,
+            DyeColor::Blue => Color::BLUE,
+            DyeColor::Brown => Color::BROWN,
+            DyeColor::Green => Color::GREEN,
+            DyeColor::Red => Color::RED,
+            DyeColor::Black => Color::BLACK,
+        }
+    }
+}
+
+// from_meta indexes ALL, so its order must match the metadata values
+const _: () = {
+    let mut i = 0;
+    while i < DyeColor::ALL.len() {
+        assert!(DyeColor::ALL[i].get_meta() as usize == i);
+        i += 1;
+    }
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dye_item_meta_is_reversed() {
+        assert_eq!(DyeColor::Black.get_dye_item_meta(), 0);
+        assert_eq!(DyeColor::White.get_dye_item_meta(), 15);
+        assert_eq!(DyeColor::Red.get_dye_item_meta(), 1);
+        for color in DyeColor::ALL {
+            assert_eq!(DyeColor::from_dye_item_meta(color.get_dye_item_meta()), Some(color));
+        }
+        assert_eq!(DyeColor::from_dye_item_meta(16), None);
+    }
+}
\ No newline at end of file
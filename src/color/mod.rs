@@ -2,6 +2,10 @@
 
 #![allow(dead_code)]
 
+mod dye_color;
+
+pub use dye_color::DyeColor;
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct Color {
     r: u8,
@@ -11,6 +15,24 @@ pub struct Color {
 }
 
 impl Color {
+    // Dye colors, using the same RGB values as PocketMine-MP
+    pub const WHITE: Color = Color::from_rgb(0xf0f0f0);
+    pub const ORANGE: Color = Color::from_rgb(0xf9801d);
+    pub const MAGENTA: Color = Color::from_rgb(0xc74ebd);
+    pub const LIGHT_BLUE: Color = Color::from_rgb(0x3ab3da);
+    pub const YELLOW: Color = Color::from_rgb(0xfed83d);
+    pub const LIME: Color = Color::from_rgb(0x80c71f);
+    pub const PINK: Color = Color::from_rgb(0xf38baa);
+    pub const GRAY: Color = Color::from_rgb(0x474f52);
+    pub const LIGHT_GRAY: Color = Color::from_rgb(0x9d9d97);
+    pub const CYAN: Color = Color::from_rgb(0x169c9c);
+    pub const PURPLE: Color = Color::from_rgb(0x8932b8);
+    pub const BLUE: Color = Color::from_rgb(0x3c44aa);
+    pub const BROWN: Color = Color::from_rgb(0x835432);
+    pub const GREEN: Color = Color::from_rgb(0x5e7c16);
+    pub const RED: Color = Color::from_rgb(0xb02e26);
+    pub const BLACK: Color = Color::from_rgb(0x1d1d21);
+
     pub const fn new(r: u8, g: u8, b: u8, a: u8) -> Self {
         Self { r, g, b, a }
     }
@@ -94,4 +116,20 @@ impl Color {
     pub const fn to_rgba(&self) -> u32 {
         ((self.r as u32) << 24) | ((self.g as u32) << 16) | ((self.b as u32) << 8) | (self.a as u32)
     }
-}
\ No newline at end of file
+
+    pub const fn from_dye(dye: DyeColor) -> Color {
+        dye.get_color()
+    }
+
+    /// Dye whose color is closest to this one by Euclidean distance in RGB space. Alpha is ignored.
+    pub fn nearest_dye(&self) -> DyeColor {
+        let distance = |dye: &DyeColor| {
+            let c = dye.get_color();
+            let dr = self.r as i32 - c.r as i32;
+            let dg = self.g as i32 - c.g as i32;
+            let db = self.b as i32 - c.b as i32;
+            dr * dr + dg * dg + db * db
+        };
+        DyeColor::ALL.into_iter().min_by_key(distance).unwrap_or(DyeColor::White)
+    }
+}
@@ -10,9 +10,9 @@ pub type LoggerAttachment = Box<dyn Fn(LogLevel, &str) + Send + Sync>;
 pub trait AttachableLogger: Logger {
     fn add_attachment(&mut self, attachment: LoggerAttachment);
 
-    // Removing exact closures is hard. Usually done via IDs or indices.
-    // PHP likely relies on object identity which doesn't map directly.
-    // fn remove_attachment(&mut self, attachment: LoggerAttachment);
+    // Removing exact closures is hard, as PHP's object identity doesn't map directly.
+    // Attachments are therefore removed by the index they were added at.
+    fn remove_attachment(&mut self, index: usize) -> Option<LoggerAttachment>;
 
     fn remove_attachments(&mut self);
 
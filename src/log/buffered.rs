@@ -1,8 +1,186 @@
 // src/log/buffered.rs
 #![allow(dead_code)]
 
+use crate::log::level::LogLevel;
 use crate::log::logger::Logger;
+use std::collections::VecDeque;
+use std::fmt;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+
+// Upper bound on the up-front allocation; larger buffers grow as records arrive
+const MAX_PREALLOC: usize = 1024;
 
 pub trait BufferedLogger: Logger {
     fn buffer(&self, buffered_fn: impl FnOnce());
-}
\ No newline at end of file
+}
+
+/// Logger that holds records in memory until they are flushed or drained.
+///
+/// Useful for capturing startup output before the real logger is configured and replaying it
+/// afterwards. The buffer is bounded: once `capacity` records are held, the oldest one is dropped
+/// to make room (see `get_dropped_count`). Records still buffered when the logger is dropped are
+/// flushed to the delegate if one is set, and discarded otherwise.
+pub struct BufferingLogger {
+    records: Mutex<VecDeque<(LogLevel, String)>>,
+    capacity: usize,
+    dropped: AtomicUsize,
+    delegate: Option<Box<dyn Logger>>,
+}
+
+impl fmt::Debug for BufferingLogger {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BufferingLogger")
+            .field("capacity", &self.capacity)
+            .field("buffered", &self.len())
+            .field("delegate", &self.delegate.as_ref().map(|_| format_args!("Box<dyn Logger>")))
+            .finish()
+    }
+}
+
+impl BufferingLogger {
+    pub fn new(capacity: usize) -> Self {
+        let capacity = capacity.max(1);
+        Self {
+            records: Mutex::new(VecDeque::with_capacity(capacity.min(MAX_PREALLOC))),
+            capacity,
+            dropped: AtomicUsize::new(0),
+            delegate: None,
+        }
+    }
+
+    pub fn with_delegate(delegate: Box<dyn Logger>, capacity: usize) -> Self {
+        let mut logger = Self::new(capacity);
+        logger.delegate = Some(delegate);
+        logger
+    }
+
+    pub fn set_delegate(&mut self, delegate: Box<dyn Logger>) {
+        self.delegate = Some(delegate);
+    }
+
+    pub fn take_delegate(&mut self) -> Option<Box<dyn Logger>> {
+        self.delegate.take()
+    }
+
+    pub fn get_capacity(&self) -> usize {
+        self.capacity
+    }
+
+    pub fn len(&self) -> usize {
+        self.records.lock().unwrap_or_else(|e| e.into_inner()).len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Number of records discarded because the buffer was full.
+    pub fn get_dropped_count(&self) -> usize {
+        self.dropped.load(Ordering::Relaxed)
+    }
+
+    /// Removes and returns all buffered records, oldest first.
+    pub fn drain(&mut self) -> Vec<(LogLevel, String)> {
+        self.records.get_mut().unwrap_or_else(|e| e.into_inner()).drain(..).collect()
+    }
+
+    /// Replays all buffered records to the delegate. Does nothing if no delegate is set.
+    pub fn flush(&mut self) {
+        if let Some(delegate) = self.delegate.take() {
+            self.flush_to(&*delegate);
+            self.delegate = Some(delegate);
+        }
+    }
+
+    /// Replays all buffered records to the given logger, emptying the buffer.
+    pub fn flush_to(&mut self, target: &dyn Logger) {
+        for (level, message) in self.drain() {
+            target.log(level, &message);
+        }
+    }
+}
+
+impl Logger for BufferingLogger {
+    fn log(&self, level: LogLevel, message: &str) {
+        // A panic while holding the lock cannot leave the queue half-updated, so keep logging
+        let mut records = self.records.lock().unwrap_or_else(|e| e.into_inner());
+        if records.len() >= self.capacity {
+            records.pop_front();
+            self.dropped.fetch_add(1, Ordering::Relaxed);
+        }
+        records.push_back((level, message.to_string()));
+    }
+}
+
+impl BufferedLogger for BufferingLogger {
+    fn buffer(&self, buffered_fn: impl FnOnce()) {
+        // Every record is buffered already; nothing reaches the delegate until flushed
+        buffered_fn();
+    }
+}
+
+impl Drop for BufferingLogger {
+    fn drop(&mut self) {
+        self.flush();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+
+    struct Recorder(Arc<Mutex<Vec<String>>>);
+
+    impl Logger for Recorder {
+        fn log(&self, _level: LogLevel, message: &str) {
+            self.0.lock().unwrap().push(message.to_string());
+        }
+    }
+
+    #[test]
+    fn overflow_drops_oldest_and_counts() {
+        let mut logger = BufferingLogger::new(2);
+        logger.log(LogLevel::Info, "one");
+        logger.log(LogLevel::Info, "two");
+        logger.log(LogLevel::Warning, "three");
+        assert_eq!(logger.len(), 2);
+        assert_eq!(logger.get_dropped_count(), 1);
+        let messages: Vec<String> = logger.drain().into_iter().map(|(_, m)| m).collect();
+        assert_eq!(messages, ["two", "three"]);
+        assert!(logger.is_empty());
+    }
+
+    #[test]
+    fn drop_flushes_to_delegate() {
+        let lines = Arc::new(Mutex::new(Vec::new()));
+        let logger = BufferingLogger::with_delegate(Box::new(Recorder(Arc::clone(&lines))), 4);
+        logger.log(LogLevel::Info, "kept");
+        drop(logger);
+        assert_eq!(*lines.lock().unwrap(), ["kept"]);
+    }
+
+    #[test]
+    fn poisoned_buffer_keeps_working() {
+        let logger = Arc::new(BufferingLogger::new(4));
+        logger.log(LogLevel::Info, "before");
+        let poisoner = Arc::clone(&logger);
+        let _ = std::thread::spawn(move || {
+            let _guard = poisoner.records.lock().unwrap();
+            panic!("poison the buffer");
+        }).join();
+        assert!(logger.records.is_poisoned());
+        logger.log(LogLevel::Info, "after");
+        assert_eq!(logger.len(), 2);
+    }
+
+    #[test]
+    fn huge_capacity_does_not_preallocate() {
+        let logger = BufferingLogger::new(usize::MAX);
+        assert_eq!(logger.get_capacity(), usize::MAX);
+        logger.log(LogLevel::Info, "fits");
+        assert_eq!(logger.len(), 1);
+        assert_eq!(BufferingLogger::new(0).get_capacity(), 1);
+    }
+}
@@ -0,0 +1,205 @@
+// src/log/file.rs
+
+#![allow(dead_code)]
+
+use crate::log::level::LogLevel;
+use crate::log::logger::Logger;
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+const SECONDS_PER_DAY: u64 = 86_400;
+
+/// When the active log file is moved aside and a fresh one is started.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RotationPolicy {
+    /// Rotate on the first write after midnight (UTC).
+    Daily,
+    /// Rotate once the active file would grow beyond the given number of bytes.
+    Size(u64),
+    /// Never rotate.
+    None,
+}
+
+struct FileState {
+    writer: Option<BufWriter<File>>,
+    // Day number (days since the Unix epoch) the active file was opened on
+    opened_day: u64,
+    bytes_written: u64,
+}
+
+/// Logger writing to a file on disk, rotating it according to a `RotationPolicy`.
+///
+/// Rotated files are renamed to `<path>.YYYY-MM-DD` (with a `.N` suffix if that name is
+/// already taken). IO failures never panic; the affected message is written to stderr instead.
+pub struct FileLogger {
+    path: PathBuf,
+    policy: RotationPolicy,
+    state: Mutex<FileState>,
+}
+
+impl std::fmt::Debug for FileLogger {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FileLogger")
+            .field("path", &self.path)
+            .field("policy", &self.policy)
+            .finish()
+    }
+}
+
+impl FileLogger {
+    pub fn new<P: AsRef<Path>>(path: P, policy: RotationPolicy) -> Self {
+        let path = path.as_ref().to_path_buf();
+        let (writer, bytes_written) = match Self::open(&path) {
+            Ok((writer, len)) => (Some(writer), len),
+            Err(e) => {
+                eprintln!("Failed to open log file {}: {}", path.display(), e);
+                (None, 0)
+            }
+        };
+        Self {
+            path,
+            policy,
+            state: Mutex::new(FileState { writer, opened_day: current_day(), bytes_written }),
+        }
+    }
+
+    pub fn get_path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn get_policy(&self) -> RotationPolicy {
+        self.policy
+    }
+
+    /// Flushes any buffered output to disk.
+    pub fn flush(&self) {
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        if let Some(Err(e)) = state.writer.as_mut().map(|writer| writer.flush()) {
+            eprintln!("Failed to flush log file {}: {}", self.path.display(), e);
+        }
+    }
+
+    fn open(path: &Path) -> io::Result<(BufWriter<File>, u64)> {
+        let file = OpenOptions::new().create(true).append(true).open(path)?;
+        let len = file.metadata()?.len();
+        Ok((BufWriter::new(file), len))
+    }
+
+    fn needs_rotation(&self, state: &FileState, incoming: u64, today: u64) -> bool {
+        match self.policy {
+            RotationPolicy::Daily => today != state.opened_day,
+            RotationPolicy::Size(limit) => state.bytes_written > 0 && state.bytes_written + incoming > limit,
+            RotationPolicy::None => false,
+        }
+    }
+
+    fn rotated_path(&self, day: u64) -> PathBuf {
+        let base = format!("{}.{}", self.path.display(), format_date(day));
+        let mut candidate = PathBuf::from(&base);
+        let mut counter = 1;
+        while candidate.exists() {
+            candidate = PathBuf::from(format!("{}.{}", base, counter));
+            counter += 1;
+        }
+        candidate
+    }
+
+    fn rotate(&self, state: &mut FileState, today: u64) -> io::Result<()> {
+        if let Some(mut writer) = state.writer.take() {
+            writer.flush()?;
+        }
+        // Daily files are named after the day they cover, size-based ones after the day they filled up
+        let stamp_day = match self.policy {
+            RotationPolicy::Daily => state.opened_day,
+            _ => today,
+        };
+        if self.path.exists() {
+            fs::rename(&self.path, self.rotated_path(stamp_day))?;
+        }
+        let (writer, len) = Self::open(&self.path)?;
+        state.writer = Some(writer);
+        state.opened_day = today;
+        state.bytes_written = len;
+        Ok(())
+    }
+
+    fn write_line(&self, state: &mut FileState, level: LogLevel, line: &str) -> io::Result<()> {
+        let today = current_day();
+        if state.writer.is_none() {
+            // A previous open failed; retry before giving up on this message
+            let (writer, len) = Self::open(&self.path)?;
+            state.writer = Some(writer);
+            state.bytes_written = len;
+        }
+        if self.needs_rotation(state, line.len() as u64, today) {
+            self.rotate(state, today)?;
+        }
+        let writer = state.writer.as_mut().ok_or_else(|| io::Error::other("log file is not open"))?;
+        writer.write_all(line.as_bytes())?;
+        state.bytes_written += line.len() as u64;
+
+        // Anything at warning or more severe must hit the disk immediately
+        if level <= LogLevel::Warning {
+            writer.flush()?;
+        }
+        Ok(())
+    }
+}
+
+impl Logger for FileLogger {
+    fn log(&self, level: LogLevel, message: &str) {
+        let line = format!("[{}] [{}] {}\n", format_timestamp(now_secs()), level.as_str(), message);
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        if let Err(e) = self.write_line(&mut state, level, &line) {
+            eprintln!("Failed to write to log file {}: {}", self.path.display(), e);
+            eprint!("{}", line);
+        }
+    }
+}
+
+impl Drop for FileLogger {
+    fn drop(&mut self) {
+        self.flush();
+    }
+}
+
+fn now_secs() -> u64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_secs()).unwrap_or(0)
+}
+
+fn current_day() -> u64 {
+    now_secs() / SECONDS_PER_DAY
+}
+
+// Converts days since the Unix epoch to a (year, month, day) civil date (proleptic Gregorian).
+fn civil_from_days(days: u64) -> (i64, u32, u32) {
+    let z = days as i64 + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+    (year, month, day)
+}
+
+fn format_date(day: u64) -> String {
+    let (year, month, day) = civil_from_days(day);
+    format!("{:04}-{:02}-{:02}", year, month, day)
+}
+
+fn format_timestamp(secs: u64) -> String {
+    let secs_of_day = secs % SECONDS_PER_DAY;
+    format!(
+        "{} {:02}:{:02}:{:02}",
+        format_date(secs / SECONDS_PER_DAY),
+        secs_of_day / 3600,
+        (secs_of_day / 60) % 60,
+        secs_of_day % 60
+    )
+}
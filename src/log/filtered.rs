@@ -0,0 +1,118 @@
+// src/log/filtered.rs
+
+#![allow(dead_code)]
+
+use crate::log::level::LogLevel;
+use crate::log::logger::Logger;
+use std::error::Error;
+use std::fmt;
+
+/// Wraps another logger and drops every message less severe than the configured level.
+pub struct FilteredLogger {
+    delegate: Box<dyn Logger>,
+    level: LogLevel,
+}
+
+impl fmt::Debug for FilteredLogger {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FilteredLogger")
+            .field("level", &self.level)
+            .field("delegate", &format_args!("Box<dyn Logger>"))
+            .finish()
+    }
+}
+
+impl FilteredLogger {
+    pub fn new(delegate: Box<dyn Logger>, level: LogLevel) -> Self {
+        Self { delegate, level }
+    }
+
+    pub fn get_level(&self) -> LogLevel {
+        self.level
+    }
+
+    /// Changes the minimum level at runtime, e.g. to switch a subsystem to debug output.
+    pub fn set_level(&mut self, level: LogLevel) {
+        self.level = level;
+    }
+
+    /// Whether a message of the given level would be forwarded to the delegate.
+    pub fn is_enabled(&self, level: LogLevel) -> bool {
+        level <= self.level
+    }
+}
+
+impl Logger for FilteredLogger {
+    fn log(&self, level: LogLevel, message: &str) {
+        if self.is_enabled(level) {
+            self.delegate.log(level, message);
+        }
+    }
+
+    fn log_exception(&self, e: &(dyn Error + Send + Sync + 'static)) {
+        // Exceptions are reported at critical level
+        if self.is_enabled(LogLevel::Critical) {
+            self.delegate.log_exception(e);
+        }
+    }
+
+    fn log_exception_with_context(&self, e: &(dyn Error + Send + Sync + 'static), context: &str) {
+        if self.is_enabled(LogLevel::Critical) {
+            self.delegate.log_exception_with_context(e, context);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::{Arc, Mutex};
+
+    struct Recorder(Arc<Mutex<Vec<LogLevel>>>);
+
+    impl Logger for Recorder {
+        fn log(&self, level: LogLevel, _message: &str) {
+            self.0.lock().unwrap().push(level);
+        }
+    }
+
+    fn filtered(level: LogLevel) -> (FilteredLogger, Arc<Mutex<Vec<LogLevel>>>) {
+        let levels = Arc::new(Mutex::new(Vec::new()));
+        (FilteredLogger::new(Box::new(Recorder(Arc::clone(&levels))), level), levels)
+    }
+
+    #[test]
+    fn forwards_only_levels_at_or_above_threshold() {
+        let (logger, levels) = filtered(LogLevel::Warning);
+        for level in LogLevel::ALL {
+            logger.log(level, "message");
+        }
+        assert_eq!(
+            *levels.lock().unwrap(),
+            [LogLevel::Emergency, LogLevel::Alert, LogLevel::Critical, LogLevel::Error, LogLevel::Warning]
+        );
+        assert!(logger.is_enabled(LogLevel::Warning));
+        assert!(!logger.is_enabled(LogLevel::Notice));
+    }
+
+    #[test]
+    fn set_level_applies_to_later_messages() {
+        let (mut logger, levels) = filtered(LogLevel::Info);
+        logger.debug("hidden");
+        logger.set_level(LogLevel::Debug);
+        logger.debug("shown");
+        assert_eq!(*levels.lock().unwrap(), [LogLevel::Debug]);
+    }
+
+    #[test]
+    fn exceptions_are_filtered_as_critical() {
+        let error = std::io::Error::other("boom");
+        let (logger, levels) = filtered(LogLevel::Alert);
+        logger.log_exception(&error);
+        assert!(levels.lock().unwrap().is_empty());
+
+        let (logger, levels) = filtered(LogLevel::Critical);
+        logger.log_exception_with_context(&error, "while testing");
+        assert_eq!(*levels.lock().unwrap(), [LogLevel::Critical]);
+    }
+}
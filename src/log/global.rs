@@ -57,6 +57,10 @@ impl GlobalLogger {
         GLOBAL_LOGGER.lock().unwrap().log_exception(e);
     }
 
+    pub fn log_exception_with_context(e: &(dyn Error + Send + Sync + 'static), context: &str) {
+        GLOBAL_LOGGER.lock().unwrap().log_exception_with_context(e, context);
+    }
+
     /// Replaces the current global logger instance.
     pub fn set(logger: Box<dyn Logger>) {
         let mut guard = GLOBAL_LOGGER.lock().unwrap();
@@ -3,8 +3,13 @@
 #![allow(dead_code)]
 
 use std::fmt;
+use std::str::FromStr;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+/// Syslog (RFC 5424) severity levels, declared from most to least severe.
+///
+/// The ordering follows the declaration, so a *smaller* level is *more* severe:
+/// `LogLevel::Error < LogLevel::Info`. A filter with threshold `t` should keep `level <= t`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[repr(u8)]
 pub enum LogLevel {
     Emergency,
@@ -18,7 +23,18 @@ pub enum LogLevel {
 }
 
 impl LogLevel {
-    pub fn to_str(&self) -> &'static str {
+    pub const ALL: [LogLevel; 8] = [
+        LogLevel::Emergency,
+        LogLevel::Alert,
+        LogLevel::Critical,
+        LogLevel::Error,
+        LogLevel::Warning,
+        LogLevel::Notice,
+        LogLevel::Info,
+        LogLevel::Debug,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
         match self {
             LogLevel::Emergency => "EMERGENCY",
             LogLevel::Alert => "ALERT",
@@ -34,6 +50,18 @@ impl LogLevel {
 
 impl fmt::Display for LogLevel {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", self.to_str())
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl FromStr for LogLevel {
+    type Err = String;
+
+    /// Parses a level name case-insensitively, e.g. "warning" or "DEBUG".
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        LogLevel::ALL
+            .into_iter()
+            .find(|level| level.as_str().eq_ignore_ascii_case(s))
+            .ok_or_else(|| format!("Unknown log level \"{}\"", s))
     }
-}
\ No newline at end of file
+}
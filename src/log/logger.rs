@@ -3,6 +3,7 @@
 #![allow(dead_code)]
 
 use crate::log::level::LogLevel;
+use std::backtrace::{Backtrace, BacktraceStatus};
 use std::error::Error;
 
 pub trait Logger: Send + Sync {
@@ -41,18 +42,35 @@ pub trait Logger: Send + Sync {
     fn log(&self, level: LogLevel, message: &str);
 
     fn log_exception(&self, e: &(dyn Error + Send + Sync + 'static)) {
-        let mut msg = format!("Error: {}", e);
-        let mut current_source = e.source();
-        while let Some(source) = current_source {
-            msg.push_str(&format!("\nCaused by: {}", source));
-            current_source = source.source();
-        }
-        self.critical(&msg);
-        // Optionally log the backtrace if available/desired, might need 'backtrace' crate
-        // For simplicity, we follow PHP's getTraceAsString roughly by just logging chained sources.
+        self.critical(&format_exception(e, None));
+    }
+
+    /// Like `log_exception`, with a note on where the error was caught (e.g. "while ticking world").
+    fn log_exception_with_context(&self, e: &(dyn Error + Send + Sync + 'static), context: &str) {
+        self.critical(&format_exception(e, Some(context)));
     }
 }
 
+/// Renders an error and its `source()` chain, one indented "Caused by" line per cause.
+/// When `RUST_BACKTRACE` is set, a backtrace of the logging call site is appended; std errors
+/// do not carry the backtrace of where they were created.
+pub fn format_exception(e: &(dyn Error + 'static), context: Option<&str>) -> String {
+    let mut msg = match context {
+        Some(context) => format!("{}: {}", context, e),
+        None => format!("Error: {}", e),
+    };
+    let mut current_source = e.source();
+    while let Some(source) = current_source {
+        msg.push_str(&format!("\n  Caused by: {}", source));
+        current_source = source.source();
+    }
+    let backtrace = Backtrace::capture();
+    if backtrace.status() == BacktraceStatus::Captured {
+        msg.push_str(&format!("\nStack trace:\n{}", backtrace));
+    }
+    msg
+}
+
 // Allow Box<dyn Logger> to be used as a Logger
 impl Logger for Box<dyn Logger> {
     fn emergency(&self, message: &str) {
@@ -85,4 +103,7 @@ impl Logger for Box<dyn Logger> {
     fn log_exception(&self, e: &(dyn Error + Send + Sync + 'static)) {
         (**self).log_exception(e)
     }
+    fn log_exception_with_context(&self, e: &(dyn Error + Send + Sync + 'static), context: &str) {
+        (**self).log_exception_with_context(e, context)
+    }
 }
\ No newline at end of file
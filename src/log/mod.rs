@@ -4,22 +4,23 @@
 
 mod attachable;
 mod buffered;
+mod file;
+mod filtered;
 mod global;
 mod level;
 mod logger;
+mod multi;
 mod prefixed;
 mod simple;
+pub mod terminal;
 
 pub use attachable::{AttachableLogger, LoggerAttachment};
-pub use buffered::BufferedLogger;
+pub use buffered::{BufferedLogger, BufferingLogger};
+pub use file::{FileLogger, RotationPolicy};
+pub use filtered::FilteredLogger;
 pub use global::GlobalLogger;
 pub use level::LogLevel;
 pub use logger::Logger;
+pub use multi::MultiLogger;
 pub use prefixed::PrefixedLogger;
 pub use simple::SimpleLogger;
-
-// Example trait implementations (Optional, depending on needs)
-// If SimpleLogger should be attachable or buffered, implement those traits here or in simple.rs
-
-// impl AttachableLogger for SimpleLogger { ... }
-// impl BufferedLogger for SimpleLogger { ... }
\ No newline at end of file
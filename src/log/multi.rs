@@ -0,0 +1,119 @@
+// src/log/multi.rs
+
+#![allow(dead_code)]
+
+use crate::log::level::LogLevel;
+use crate::log::logger::Logger;
+use std::error::Error;
+use std::fmt;
+use std::panic::{self, AssertUnwindSafe};
+
+/// Fans every call out to several loggers, e.g. console and file at the same time.
+pub struct MultiLogger {
+    loggers: Vec<Box<dyn Logger>>,
+}
+
+impl fmt::Debug for MultiLogger {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MultiLogger")
+            .field("loggers", &self.loggers.len())
+            .finish()
+    }
+}
+
+impl MultiLogger {
+    pub fn new(loggers: Vec<Box<dyn Logger>>) -> Self {
+        Self { loggers }
+    }
+
+    pub fn add_logger(&mut self, logger: Box<dyn Logger>) {
+        self.loggers.push(logger);
+    }
+
+    pub fn get_loggers(&self) -> &[Box<dyn Logger>] {
+        &self.loggers
+    }
+
+    // Calls `f` on each child in order. A panicking child is skipped so the others still log.
+    fn each<F: Fn(&dyn Logger)>(&self, f: F) {
+        for logger in &self.loggers {
+            if panic::catch_unwind(AssertUnwindSafe(|| f(&**logger))).is_err() {
+                eprintln!("A logger in MultiLogger panicked while logging; message skipped for that logger");
+            }
+        }
+    }
+}
+
+impl Logger for MultiLogger {
+    fn emergency(&self, message: &str) {
+        self.each(|l| l.emergency(message));
+    }
+    fn alert(&self, message: &str) {
+        self.each(|l| l.alert(message));
+    }
+    fn critical(&self, message: &str) {
+        self.each(|l| l.critical(message));
+    }
+    fn error(&self, message: &str) {
+        self.each(|l| l.error(message));
+    }
+    fn warning(&self, message: &str) {
+        self.each(|l| l.warning(message));
+    }
+    fn notice(&self, message: &str) {
+        self.each(|l| l.notice(message));
+    }
+    fn info(&self, message: &str) {
+        self.each(|l| l.info(message));
+    }
+    fn debug(&self, message: &str) {
+        self.each(|l| l.debug(message));
+    }
+    fn log(&self, level: LogLevel, message: &str) {
+        self.each(|l| l.log(level, message));
+    }
+    fn log_exception(&self, e: &(dyn Error + Send + Sync + 'static)) {
+        self.each(|l| l.log_exception(e));
+    }
+    fn log_exception_with_context(&self, e: &(dyn Error + Send + Sync + 'static), context: &str) {
+        self.each(|l| l.log_exception_with_context(e, context));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::{Arc, Mutex};
+
+    struct Recorder(Arc<Mutex<Vec<String>>>);
+
+    impl Logger for Recorder {
+        fn log(&self, _level: LogLevel, message: &str) {
+            self.0.lock().unwrap().push(message.to_string());
+        }
+    }
+
+    struct Panicking;
+
+    impl Logger for Panicking {
+        fn log(&self, _level: LogLevel, _message: &str) {
+            panic!("logger failure");
+        }
+    }
+
+    #[test]
+    fn panicking_child_does_not_stop_the_others() {
+        let first = Arc::new(Mutex::new(Vec::new()));
+        let last = Arc::new(Mutex::new(Vec::new()));
+        let logger = MultiLogger::new(vec![
+            Box::new(Recorder(Arc::clone(&first))),
+            Box::new(Panicking),
+            Box::new(Recorder(Arc::clone(&last))),
+        ]);
+
+        logger.info("one");
+        logger.log(LogLevel::Warning, "two");
+        assert_eq!(*first.lock().unwrap(), ["one", "two"]);
+        assert_eq!(*last.lock().unwrap(), ["one", "two"]);
+    }
+}
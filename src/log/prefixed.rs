@@ -1,7 +1,8 @@
 #![allow(dead_code)]
 
+use crate::log::attachable::{AttachableLogger, LoggerAttachment};
 use crate::log::level::LogLevel;
-use crate::log::logger::Logger;
+use crate::log::logger::{format_exception, Logger};
 use std::error::Error;
 use std::fmt; // Import fmt
 
@@ -9,6 +10,7 @@ use std::fmt; // Import fmt
 pub struct PrefixedLogger {
     delegate: Box<dyn Logger>,
     prefix: String,
+    attachments: Vec<LoggerAttachment>,
 }
 
 // Manual implementation of Debug
@@ -19,6 +21,7 @@ impl fmt::Debug for PrefixedLogger {
             // We can't easily debug the concrete type behind the Box<dyn Logger>
             // So we just indicate its presence and type.
             .field("delegate", &format_args!("Box<dyn Logger>"))
+            .field("attachments", &self.attachments.len())
             .finish()
     }
 }
@@ -26,7 +29,7 @@ impl fmt::Debug for PrefixedLogger {
 
 impl PrefixedLogger {
     pub fn new(delegate: Box<dyn Logger>, prefix: String) -> Self {
-        Self { delegate, prefix }
+        Self { delegate, prefix, attachments: Vec::new() }
     }
 
     pub fn get_prefix(&self) -> &str {
@@ -36,16 +39,92 @@ impl PrefixedLogger {
     pub fn set_prefix(&mut self, prefix: String) {
         self.prefix = prefix;
     }
+
+    fn notify_attachments(&self, level: LogLevel, message: &str) {
+        for attachment in &self.attachments {
+            attachment(level, message);
+        }
+    }
 }
 
 impl Logger for PrefixedLogger {
     fn log(&self, level: LogLevel, message: &str) {
         let prefixed_message = format!("[{}] {}", self.prefix, message);
         self.delegate.log(level, &prefixed_message);
+        self.notify_attachments(level, &prefixed_message);
     }
 
+    // The delegate formats the exception itself; attachments get the same text with the prefix
     fn log_exception(&self, e: &(dyn Error + Send + Sync + 'static)) {
-        // Prefixing doesn't usually apply to the structured exception log
         self.delegate.log_exception(e);
+        let text = format_exception(e, None);
+        self.notify_attachments(LogLevel::Critical, &format!("[{}] {}", self.prefix, text.trim_end()));
+    }
+
+    fn log_exception_with_context(&self, e: &(dyn Error + Send + Sync + 'static), context: &str) {
+        self.delegate.log_exception_with_context(e, context);
+        let text = format_exception(e, Some(context));
+        self.notify_attachments(LogLevel::Critical, &format!("[{}] {}", self.prefix, text.trim_end()));
+    }
+}
+
+impl AttachableLogger for PrefixedLogger {
+    fn add_attachment(&mut self, attachment: LoggerAttachment) {
+        self.attachments.push(attachment);
+    }
+
+    fn remove_attachment(&mut self, index: usize) -> Option<LoggerAttachment> {
+        if index < self.attachments.len() {
+            Some(self.attachments.remove(index))
+        } else {
+            None
+        }
+    }
+
+    fn remove_attachments(&mut self) {
+        self.attachments.clear();
+    }
+
+    fn get_attachments(&self) -> &[LoggerAttachment] {
+        &self.attachments
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::log::simple::SimpleLogger;
+    use std::sync::{Arc, Mutex};
+
+    type Records = Arc<Mutex<Vec<(LogLevel, String)>>>;
+
+    fn recorder() -> (Records, LoggerAttachment) {
+        let records: Records = Arc::new(Mutex::new(Vec::new()));
+        let sink = Arc::clone(&records);
+        (records, Box::new(move |level, message| sink.lock().unwrap().push((level, message.to_string()))))
+    }
+
+    #[test]
+    fn exceptions_reach_attachments_as_critical() {
+        let (inner, inner_attachment) = recorder();
+        let mut delegate = SimpleLogger::with_colors(false);
+        delegate.add_attachment(inner_attachment);
+
+        let (outer, outer_attachment) = recorder();
+        let mut logger = PrefixedLogger::new(Box::new(delegate), "World".to_string());
+        logger.add_attachment(outer_attachment);
+
+        let error = std::io::Error::other("disk full");
+        logger.log_exception_with_context(&error, "while saving");
+
+        let inner = inner.lock().unwrap();
+        assert_eq!(inner.len(), 1);
+        assert_eq!(inner[0].0, LogLevel::Critical);
+        assert!(inner[0].1.starts_with("while saving: disk full"), "{}", inner[0].1);
+
+        let outer = outer.lock().unwrap();
+        assert_eq!(outer.len(), 1);
+        assert_eq!(outer[0].0, LogLevel::Critical);
+        assert!(outer[0].1.starts_with("[World] while saving: disk full"), "{}", outer[0].1);
+    }
+}
@@ -2,17 +2,78 @@
 
 #![allow(dead_code)]
 
+use crate::log::attachable::{AttachableLogger, LoggerAttachment};
 use crate::log::level::LogLevel;
-use crate::log::logger::Logger;
+use crate::log::logger::{format_exception, Logger};
+use crate::log::terminal;
 use std::error::Error;
-use std::fmt::Write;
+use std::fmt;
+use std::io::IsTerminal;
 
-#[derive(Debug, Clone, Default)]
-pub struct SimpleLogger;
+pub struct SimpleLogger {
+    colors: bool,
+    attachments: Vec<LoggerAttachment>,
+}
+
+impl fmt::Debug for SimpleLogger {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SimpleLogger")
+            .field("colors", &self.colors)
+            .field("attachments", &self.attachments.len())
+            .finish()
+    }
+}
 
 impl SimpleLogger {
+    /// Creates a logger that emits ANSI colors only when stdout is a terminal.
     pub fn new() -> Self {
-        SimpleLogger {}
+        Self::with_colors(std::io::stdout().is_terminal())
+    }
+
+    /// Creates a logger with colors explicitly enabled or disabled. When disabled,
+    /// `§` format codes are stripped so redirected output stays plain text.
+    pub fn with_colors(enabled: bool) -> Self {
+        SimpleLogger { colors: enabled, attachments: Vec::new() }
+    }
+
+    pub fn has_colors(&self) -> bool {
+        self.colors
+    }
+
+    pub fn set_colors(&mut self, enabled: bool) {
+        self.colors = enabled;
+    }
+
+    fn format_line(&self, level: LogLevel, message: &str) -> String {
+        if self.colors {
+            format!("{}[{}] {}{}", terminal::level_color(level), level.as_str(), terminal::to_ansi_for_level(message, level), terminal::RESET)
+        } else {
+            format!("[{}] {}", level.as_str(), terminal::strip_format(message))
+        }
+    }
+
+    // Exceptions skip `§` handling; the whole block is colored as critical.
+    fn print_exception(&self, e: &(dyn Error + Send + Sync + 'static), context: Option<&str>) {
+        let text = format_exception(e, context);
+        let text = text.trim_end();
+        if self.colors {
+            println!("{}[{}] {}{}", terminal::level_color(LogLevel::Critical), LogLevel::Critical, text, terminal::RESET);
+        } else {
+            println!("[{}] {}", LogLevel::Critical, text);
+        }
+        self.notify_attachments(LogLevel::Critical, text);
+    }
+
+    fn notify_attachments(&self, level: LogLevel, message: &str) {
+        for attachment in &self.attachments {
+            attachment(level, message);
+        }
+    }
+}
+
+impl Default for SimpleLogger {
+    fn default() -> Self {
+        Self::new()
     }
 }
 
@@ -20,30 +81,37 @@ impl Logger for SimpleLogger {
     fn log(&self, level: LogLevel, message: &str) {
         // In a real server, this should go to a proper logging framework
         // or file/console handler. Stdout might get messy.
-        println!("[{}] {}", level.to_str(), message);
+        println!("{}", self.format_line(level, message));
+        self.notify_attachments(level, message);
     }
 
     fn log_exception(&self, e: &(dyn Error + Send + Sync + 'static)) {
-        // Custom formatting closer to PHP's default exception output
-        let mut output = String::new();
-        writeln!(output, "[{}] {}: {}", LogLevel::Critical, std::any::type_name_of_val(e), e)
-            .expect("Failed to write exception header");
-
-        let mut current_source = e.source();
-        let mut cause_level = 1;
-        while let Some(source) = current_source {
-            writeln!(output, "Caused by ({}) {}: {}", cause_level, std::any::type_name_of_val(source), source)
-                .expect("Failed to write exception cause");
-            current_source = source.source();
-            cause_level += 1;
+        self.print_exception(e, None);
+    }
+
+    fn log_exception_with_context(&self, e: &(dyn Error + Send + Sync + 'static), context: &str) {
+        self.print_exception(e, Some(context));
+    }
+}
+
+impl AttachableLogger for SimpleLogger {
+    fn add_attachment(&mut self, attachment: LoggerAttachment) {
+        self.attachments.push(attachment);
+    }
+
+    fn remove_attachment(&mut self, index: usize) -> Option<LoggerAttachment> {
+        if index < self.attachments.len() {
+            Some(self.attachments.remove(index))
+        } else {
+            None
         }
+    }
 
-        // Backtrace would go here if enabled/desired
-        // let backtrace = std::backtrace::Backtrace::capture();
-        // if backtrace.status() == std::backtrace::BacktraceStatus::Captured {
-        //     writeln!(output, "Stack trace:\n{}", backtrace).expect("Failed to write backtrace");
-        // }
+    fn remove_attachments(&mut self) {
+        self.attachments.clear();
+    }
 
-        println!("{}", output.trim_end()); // Print the formatted string
+    fn get_attachments(&self) -> &[LoggerAttachment] {
+        &self.attachments
     }
-}
\ No newline at end of file
+}
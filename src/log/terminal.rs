@@ -0,0 +1,109 @@
+// src/log/terminal.rs
+
+#![allow(dead_code)]
+
+use crate::log::level::LogLevel;
+
+pub const FORMAT_PREFIX: char = '§';
+pub const RESET: &str = "\u{1b}[0m";
+
+/// ANSI sequence used to color a whole log line of the given level.
+pub fn level_color(level: LogLevel) -> &'static str {
+    match level {
+        LogLevel::Debug => "\u{1b}[90m",
+        LogLevel::Info => "\u{1b}[97m",
+        LogLevel::Notice => "\u{1b}[96m",
+        LogLevel::Warning => "\u{1b}[93m",
+        LogLevel::Error | LogLevel::Critical | LogLevel::Alert | LogLevel::Emergency => "\u{1b}[91m",
+    }
+}
+
+// Maps a Minecraft format code (the character following '§') to its ANSI sequence.
+fn ansi_for_code(code: char) -> Option<&'static str> {
+    let seq = match code.to_ascii_lowercase() {
+        '0' => "\u{1b}[30m",
+        '1' => "\u{1b}[34m",
+        '2' => "\u{1b}[32m",
+        '3' => "\u{1b}[36m",
+        '4' => "\u{1b}[31m",
+        '5' => "\u{1b}[35m",
+        '6' => "\u{1b}[33m",
+        '7' => "\u{1b}[37m",
+        '8' => "\u{1b}[90m",
+        '9' => "\u{1b}[94m",
+        'a' => "\u{1b}[92m",
+        'b' => "\u{1b}[96m",
+        'c' => "\u{1b}[91m",
+        'd' => "\u{1b}[95m",
+        'e' => "\u{1b}[93m",
+        'f' => "\u{1b}[97m",
+        'k' => "", // Obfuscated text has no terminal equivalent
+        'l' => "\u{1b}[1m",
+        'm' => "\u{1b}[9m",
+        'n' => "\u{1b}[4m",
+        'o' => "\u{1b}[3m",
+        'r' => RESET,
+        _ => return None,
+    };
+    Some(seq)
+}
+
+// Walks the message, letting `replace` write the output for each recognised `§x` pair given
+// its ANSI sequence.
+fn map_format_codes(message: &str, replace: impl Fn(&mut String, &'static str)) -> String {
+    let mut output = String::with_capacity(message.len());
+    let mut chars = message.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c == FORMAT_PREFIX && let Some(seq) = chars.peek().copied().and_then(ansi_for_code) {
+            chars.next();
+            replace(&mut output, seq);
+            continue;
+        }
+        output.push(c);
+    }
+    output
+}
+
+/// Translates Minecraft `§` format codes into ANSI escape sequences. `§r` resets to the
+/// terminal's default style.
+pub fn to_ansi(message: &str) -> String {
+    map_format_codes(message, |output, seq| output.push_str(seq))
+}
+
+/// Like `to_ansi`, for text inside a line colored with `level_color(level)`: `§r` resets the
+/// style and then restores that level color instead of the terminal default.
+pub fn to_ansi_for_level(message: &str, level: LogLevel) -> String {
+    map_format_codes(message, |output, seq| {
+        output.push_str(seq);
+        if seq == RESET {
+            output.push_str(level_color(level));
+        }
+    })
+}
+
+/// Removes Minecraft `§` format codes, leaving plain text.
+pub fn strip_format(message: &str) -> String {
+    map_format_codes(message, |_, _| {})
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reset_restores_level_color() {
+        let warning = level_color(LogLevel::Warning);
+        assert_eq!(
+            to_ansi_for_level("§cred§r back", LogLevel::Warning),
+            format!("\u{1b}[91mred{}{} back", RESET, warning)
+        );
+        assert_eq!(to_ansi("§cred§r back"), format!("\u{1b}[91mred{} back", RESET));
+    }
+
+    #[test]
+    fn unknown_codes_are_kept_and_known_ones_stripped() {
+        assert_eq!(strip_format("§lbold§r §zplain§"), "bold §zplain§");
+        assert_eq!(to_ansi("§Z"), "§Z");
+        assert_eq!(to_ansi("§Ayes"), "\u{1b}[92myes");
+    }
+}
\ No newline at end of file
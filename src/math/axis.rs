@@ -2,6 +2,8 @@
 
 #![allow(dead_code)]
 
+use crate::math::facing::Facing;
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[repr(u8)]
 pub enum Axis {
@@ -11,6 +13,8 @@ pub enum Axis {
 }
 
 impl Axis {
+    pub const ALL: [Axis; 3] = [Axis::Y, Axis::Z, Axis::X];
+
     pub fn from_int(axis: u8) -> Option<Self> {
         match axis {
             0 => Some(Axis::Y),
@@ -20,6 +24,19 @@ impl Axis {
         }
     }
 
+    pub const fn from_facing(facing: Facing) -> Axis {
+        Facing::axis(facing)
+    }
+
+    /// The two axes perpendicular to this one.
+    pub const fn perpendiculars(self) -> [Axis; 2] {
+        match self {
+            Axis::Y => [Axis::Z, Axis::X],
+            Axis::Z => [Axis::Y, Axis::X],
+            Axis::X => [Axis::Y, Axis::Z],
+        }
+    }
+
     pub fn to_string(axis: Axis) -> Option<&'static str> {
         match axis {
             Axis::Y => Some("y"),
@@ -6,6 +6,7 @@ use crate::math::{
     vector3::Vector3,
     facing::Facing,
     axis::Axis,
+    error::MathError,
     ray_trace_result::RayTraceResult
 };
 use std::fmt;
@@ -21,12 +22,37 @@ pub struct AxisAlignedBB {
 }
 
 impl AxisAlignedBB {
+    /// Panicking convenience over `try_new`, matching PocketMine's constructor. Use `try_new`
+    /// for bounds computed from untrusted input.
     pub fn new(min_x: f64, min_y: f64, min_z: f64, max_x: f64, max_y: f64, max_z: f64) -> Self {
-        // In Rust, we might prefer returning a Result or panicking. Let's panic for now to match PHP.
-        if min_x > max_x { panic!("min_x {} is larger than max_x {}", min_x, max_x); }
-        if min_y > max_y { panic!("min_y {} is larger than max_y {}", min_y, max_y); }
-        if min_z > max_z { panic!("min_z {} is larger than max_z {}", min_z, max_z); }
-        Self { min_x, min_y, min_z, max_x, max_y, max_z }
+        Self::try_new(min_x, min_y, min_z, max_x, max_y, max_z).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    pub fn try_new(min_x: f64, min_y: f64, min_z: f64, max_x: f64, max_y: f64, max_z: f64) -> Result<Self, MathError> {
+        for (axis, min, max) in [(Axis::X, min_x, max_x), (Axis::Y, min_y, max_y), (Axis::Z, min_z, max_z)] {
+            if min > max || min.is_nan() || max.is_nan() {
+                return Err(MathError::InvalidBounds { axis, min, max });
+            }
+        }
+        Ok(Self { min_x, min_y, min_z, max_x, max_y, max_z })
+    }
+
+    /// Builds the box spanned by two opposite corners, given in any order. Fails only if a
+    /// coordinate is NaN.
+    pub fn from_points(a: Vector3, b: Vector3) -> Result<Self, MathError> {
+        // f64::min/max would silently drop a NaN; ordering by hand keeps it for try_new to reject
+        let order = |a: f64, b: f64| if a <= b { (a, b) } else { (b, a) };
+        let (min_x, max_x) = order(a.x, b.x);
+        let (min_y, max_y) = order(a.y, b.y);
+        let (min_z, max_z) = order(a.z, b.z);
+        Self::try_new(min_x, min_y, min_z, max_x, max_y, max_z)
+    }
+
+    /// Builds a box centered on `center` extending `half_extents` along each axis. Negative
+    /// extents are treated as their absolute value.
+    pub fn from_center(center: Vector3, half_extents: Vector3) -> Result<Self, MathError> {
+        let half = half_extents.abs();
+        Self::from_points(center - half, center + half)
     }
 
     pub fn add_coord(&self, x: f64, y: f64, z: f64) -> Self {
@@ -212,6 +238,13 @@ impl AxisAlignedBB {
         // vector.z > self.min_z && vector.z < self.max_z
     }
 
+    /// Whether the point lies inside the box or on its boundary. Unlike `is_vector_inside`,
+    /// the faces count as inside.
+    pub fn contains_point(&self, p: &Vector3) -> bool {
+        p.x >= self.min_x && p.x <= self.max_x &&
+        p.y >= self.min_y && p.y <= self.max_y &&
+        p.z >= self.min_z && p.z <= self.max_z
+    }
 
     pub fn get_average_edge_length(&self) -> f64 {
         (self.get_x_length() + self.get_y_length() + self.get_z_length()) / 3.0
@@ -232,6 +265,18 @@ impl AxisAlignedBB {
         self.get_x_length() * self.get_y_length() * self.get_z_length()
     }
 
+    pub fn get_surface_area(&self) -> f64 {
+        let x_len = self.get_x_length();
+        let y_len = self.get_y_length();
+        let z_len = self.get_z_length();
+        2.0 * (x_len * y_len + y_len * z_len + z_len * x_len)
+    }
+
+    /// Dimensions of the box along each axis.
+    pub fn get_size(&self) -> Vector3 {
+        Vector3::new(self.get_x_length(), self.get_y_length(), self.get_z_length())
+    }
+
     pub fn is_vector_in_yz(&self, vector: &Vector3) -> bool {
         vector.y >= self.min_y && vector.y <= self.max_y && vector.z >= self.min_z && vector.z <= self.max_z
     }
@@ -298,3 +343,55 @@ impl fmt::Display for AxisAlignedBB {
                self.min_x, self.min_y, self.min_z, self.max_x, self.max_y, self.max_z)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn try_new_rejects_inverted_and_nan_bounds() {
+        assert_eq!(
+            AxisAlignedBB::try_new(0.0, 5.0, 0.0, 1.0, 4.0, 1.0).unwrap_err(),
+            MathError::InvalidBounds { axis: Axis::Y, min: 5.0, max: 4.0 }
+        );
+        assert!(matches!(
+            AxisAlignedBB::try_new(0.0, 0.0, f64::NAN, 1.0, 1.0, 1.0),
+            Err(MathError::InvalidBounds { axis: Axis::Z, .. })
+        ));
+        // Flat boxes are allowed
+        assert!(AxisAlignedBB::try_new(1.0, 1.0, 1.0, 1.0, 2.0, 2.0).is_ok());
+    }
+
+    #[test]
+    fn from_points_orders_inverted_corners() {
+        let bb = AxisAlignedBB::from_points(Vector3::new(3.0, -1.0, 8.0), Vector3::new(-2.0, 4.0, 6.0)).unwrap();
+        assert_eq!(bb, AxisAlignedBB::new(-2.0, -1.0, 6.0, 3.0, 4.0, 8.0));
+        assert!(bb.contains_point(&Vector3::new(3.0, 4.0, 8.0)));
+        assert!(!bb.contains_point(&Vector3::new(3.1, 0.0, 7.0)));
+    }
+
+    #[test]
+    fn from_points_rejects_nan() {
+        let nan = Vector3::new(f64::NAN, 0.0, 0.0);
+        let one = Vector3::new(1.0, 1.0, 1.0);
+        assert!(AxisAlignedBB::from_points(nan, one).is_err());
+        assert!(AxisAlignedBB::from_points(one, nan).is_err());
+    }
+
+    #[test]
+    fn from_center_uses_absolute_extents() {
+        let bb = AxisAlignedBB::from_center(Vector3::new(0.0, 10.0, 0.0), Vector3::new(-1.0, 2.0, 0.5)).unwrap();
+        assert_eq!(bb, AxisAlignedBB::new(-1.0, 8.0, -0.5, 1.0, 12.0, 0.5));
+    }
+
+    #[test]
+    fn size_and_surface_area() {
+        let bb = AxisAlignedBB::new(-1.0, 0.0, 2.0, 1.0, 3.0, 6.0);
+        assert_eq!(bb.get_size(), Vector3::new(2.0, 3.0, 4.0));
+        assert_eq!(bb.get_surface_area(), 2.0 * (2.0 * 3.0 + 3.0 * 4.0 + 4.0 * 2.0));
+        assert_eq!(AxisAlignedBB::one().get_surface_area(), 6.0);
+        let flat = AxisAlignedBB::new(0.0, 0.0, 0.0, 2.0, 0.0, 5.0);
+        assert_eq!(flat.get_surface_area(), 20.0);
+        assert_eq!(flat.get_volume(), 0.0);
+    }
+}
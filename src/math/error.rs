@@ -0,0 +1,36 @@
+// src/math/error.rs
+
+#![allow(dead_code)]
+
+use crate::math::axis::Axis;
+use std::error::Error;
+use std::fmt;
+
+/// Error for geometric edge cases in the fallible math APIs.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MathError {
+    /// The matrix has no inverse (its determinant is zero).
+    SingularMatrix,
+    /// The operation is only defined for square matrices.
+    NotSquare { rows: usize, columns: usize },
+    /// The minimum on `axis` is greater than the maximum, or one of them is NaN.
+    InvalidBounds { axis: Axis, min: f64, max: f64 },
+    /// A direction was required but the vector has (near) zero length.
+    ZeroLengthVector,
+}
+
+impl fmt::Display for MathError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MathError::SingularMatrix => write!(f, "Matrix is singular and cannot be inverted"),
+            MathError::NotSquare { rows, columns } => write!(f, "Expected a square matrix, but got {}x{}", rows, columns),
+            MathError::InvalidBounds { axis, min, max } => {
+                let name = Axis::to_string(*axis).unwrap_or("?");
+                write!(f, "min_{} {} is larger than max_{} {}", name, min, name, max)
+            }
+            MathError::ZeroLengthVector => write!(f, "Vector has zero length"),
+        }
+    }
+}
+
+impl Error for MathError {}
@@ -84,6 +84,32 @@ impl Facing {
         }
     }
 
+    /// Bedrock block face index: 0 = down, 1 = up, 2 = north, 3 = south, 4 = west, 5 = east.
+    /// Spelled out rather than derived from the discriminants so reordering the enum cannot
+    /// change the wire value.
+    pub const fn to_protocol_id(facing: Facing) -> u8 {
+        match facing {
+            Facing::Down => 0,
+            Facing::Up => 1,
+            Facing::North => 2,
+            Facing::South => 3,
+            Facing::West => 4,
+            Facing::East => 5,
+        }
+    }
+
+    pub const fn from_protocol_id(id: u8) -> Option<Facing> {
+        match id {
+            0 => Some(Facing::Down),
+            1 => Some(Facing::Up),
+            2 => Some(Facing::North),
+            3 => Some(Facing::South),
+            4 => Some(Facing::West),
+            5 => Some(Facing::East),
+            _ => None,
+        }
+    }
+
     pub const fn axis(direction: Facing) -> Axis {
         // Safe because enum repr ensures values 0..5
         unsafe { std::mem::transmute::<u8, Axis>((direction as u8) >> 1) }
@@ -146,4 +172,13 @@ impl Facing {
     pub fn get_offset(facing: Facing) -> [i8; 3] {
         Facing::OFFSET[facing as usize]
     }
-}
\ No newline at end of file
+}
+
+// OFFSET, get_offset and from_int index by discriminant, which must stay equal to the protocol ID
+const _: () = {
+    let mut i = 0;
+    while i < Facing::ALL.len() {
+        assert!(Facing::ALL[i] as u8 == Facing::to_protocol_id(Facing::ALL[i]));
+        i += 1;
+    }
+};
\ No newline at end of file
@@ -2,8 +2,14 @@
 
 #![allow(dead_code)]
 
+use crate::math::{error::MathError, vector3::Vector3};
 use std::{fmt, ops::{Index, IndexMut}};
 
+/// Dense matrix stored row-major: `data[row][column]`.
+///
+/// The 3D transform constructors build 4x4 matrices for column vectors, i.e. a point is
+/// transformed as `M * [x, y, z, 1]^T` and the translation lives in the last column. To apply
+/// `a` and then `b`, compose them as `b.product(&a)`.
 #[derive(Clone, Debug)]
 pub struct Matrix {
     data: Vec<Vec<f64>>,
@@ -27,6 +33,83 @@ impl Matrix {
         Self { data, rows: r, columns: c }
     }
 
+    fn from_rows(rows: [[f64; 4]; 4]) -> Self {
+        Self { data: rows.iter().map(|row| row.to_vec()).collect(), rows: 4, columns: 4 }
+    }
+
+    pub fn identity() -> Self {
+        Self::from_rows([
+            [1.0, 0.0, 0.0, 0.0],
+            [0.0, 1.0, 0.0, 0.0],
+            [0.0, 0.0, 1.0, 0.0],
+            [0.0, 0.0, 0.0, 1.0],
+        ])
+    }
+
+    pub fn translation(x: f64, y: f64, z: f64) -> Self {
+        Self::from_rows([
+            [1.0, 0.0, 0.0, x],
+            [0.0, 1.0, 0.0, y],
+            [0.0, 0.0, 1.0, z],
+            [0.0, 0.0, 0.0, 1.0],
+        ])
+    }
+
+    pub fn scale(x: f64, y: f64, z: f64) -> Self {
+        Self::from_rows([
+            [x, 0.0, 0.0, 0.0],
+            [0.0, y, 0.0, 0.0],
+            [0.0, 0.0, z, 0.0],
+            [0.0, 0.0, 0.0, 1.0],
+        ])
+    }
+
+    /// Counter-clockwise rotation about the X axis when looking from +X towards the origin.
+    pub fn rotation_x(radians: f64) -> Self {
+        let (sin, cos) = radians.sin_cos();
+        Self::from_rows([
+            [1.0, 0.0, 0.0, 0.0],
+            [0.0, cos, -sin, 0.0],
+            [0.0, sin, cos, 0.0],
+            [0.0, 0.0, 0.0, 1.0],
+        ])
+    }
+
+    pub fn rotation_y(radians: f64) -> Self {
+        let (sin, cos) = radians.sin_cos();
+        Self::from_rows([
+            [cos, 0.0, sin, 0.0],
+            [0.0, 1.0, 0.0, 0.0],
+            [-sin, 0.0, cos, 0.0],
+            [0.0, 0.0, 0.0, 1.0],
+        ])
+    }
+
+    pub fn rotation_z(radians: f64) -> Self {
+        let (sin, cos) = radians.sin_cos();
+        Self::from_rows([
+            [cos, -sin, 0.0, 0.0],
+            [sin, cos, 0.0, 0.0],
+            [0.0, 0.0, 1.0, 0.0],
+            [0.0, 0.0, 0.0, 1.0],
+        ])
+    }
+
+    /// Right-handed view matrix (as in `gluLookAt`): moves `eye` to the origin looking down -Z
+    /// with `up` pointing along +Y. Fails with `ZeroLengthVector` if `eye == target`, `up` is zero
+    /// or the view direction is parallel to `up`, since no basis can be built from them.
+    pub fn look_at(eye: &Vector3, target: &Vector3, up: &Vector3) -> Result<Self, MathError> {
+        let forward = target.subtract_vector(eye).try_normalize()?;
+        let side = forward.cross(&up.try_normalize()?).try_normalize()?;
+        let true_up = side.cross(&forward);
+        Ok(Self::from_rows([
+            [side.x, side.y, side.z, -side.dot(eye)],
+            [true_up.x, true_up.y, true_up.z, -true_up.dot(eye)],
+            [-forward.x, -forward.y, -forward.z, forward.dot(eye)],
+            [0.0, 0.0, 0.0, 1.0],
+        ]))
+    }
+
     pub fn set(&mut self, new_data: &[Vec<f64>]) {
         // Reinitialize data grid and copy values
         self.data = vec![vec![0.0; self.columns]; self.rows];
@@ -154,6 +237,46 @@ impl Matrix {
             _ => Err("Determinant calculation not implemented for this size".to_string())
         }
     }
+
+    /// Inverse by Gauss-Jordan elimination with partial pivoting. Pivots with an absolute value
+    /// below 1e-12 are treated as zero, so nearly singular matrices are rejected too.
+    pub fn inverse(&self) -> Result<Matrix, MathError> {
+        if !self.is_square() {
+            return Err(MathError::NotSquare { rows: self.rows, columns: self.columns });
+        }
+        let n = self.rows;
+        let mut a = self.data.clone();
+        let mut inv = Matrix::new(n, n, None);
+        for i in 0..n {
+            inv.data[i][i] = 1.0;
+        }
+
+        for col in 0..n {
+            let pivot_row = (col..n)
+                .max_by(|&x, &y| a[x][col].abs().total_cmp(&a[y][col].abs()))
+                .unwrap_or(col);
+            let pivot = a[pivot_row][col];
+            if pivot.abs() < 1e-12 || pivot.is_nan() {
+                return Err(MathError::SingularMatrix);
+            }
+            a.swap(col, pivot_row);
+            inv.data.swap(col, pivot_row);
+
+            a[col].iter_mut().for_each(|v| *v /= pivot);
+            inv.data[col].iter_mut().for_each(|v| *v /= pivot);
+            let pivot_a = a[col].clone();
+            let pivot_inv = inv.data[col].clone();
+            for (r, (row, inv_row)) in a.iter_mut().zip(inv.data.iter_mut()).enumerate() {
+                let factor = row[col];
+                if r == col || factor == 0.0 {
+                    continue;
+                }
+                row.iter_mut().zip(&pivot_a).for_each(|(v, p)| *v -= factor * p);
+                inv_row.iter_mut().zip(&pivot_inv).for_each(|(v, p)| *v -= factor * p);
+            }
+        }
+        Ok(inv)
+    }
 }
 
 impl Index<usize> for Matrix {
@@ -181,4 +304,74 @@ impl fmt::Display for Matrix {
         }
         write!(f, "Matrix({}x{};{})", self.rows, self.columns, s)
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(a: &Matrix, b: &Matrix) {
+        assert_eq!((a.rows(), a.columns()), (b.rows(), b.columns()));
+        for r in 0..a.rows() {
+            for c in 0..a.columns() {
+                assert!((a[r][c] - b[r][c]).abs() < 1e-9, "{} != {}", a, b);
+            }
+        }
+    }
+
+    fn apply(m: &Matrix, p: &Vector3) -> Vector3 {
+        let column = Matrix::new(4, 1, Some(&[vec![p.x], vec![p.y], vec![p.z], vec![1.0]]));
+        let r = m.product(&column).unwrap();
+        Vector3::new(r[0][0], r[1][0], r[2][0])
+    }
+
+    fn sample() -> Matrix {
+        Matrix::translation(1.0, -2.0, 3.0)
+            .product(&Matrix::rotation_y(0.7)).unwrap()
+            .product(&Matrix::scale(2.0, 0.5, 4.0)).unwrap()
+    }
+
+    #[test]
+    fn identity_is_neutral() {
+        let m = sample();
+        assert_close(&Matrix::identity().product(&m).unwrap(), &m);
+        assert_close(&m.product(&Matrix::identity()).unwrap(), &m);
+    }
+
+    #[test]
+    fn inverse_round_trips() {
+        let m = sample();
+        let inverse = m.inverse().unwrap();
+        assert_close(&m.product(&inverse).unwrap(), &Matrix::identity());
+        assert_close(&inverse.inverse().unwrap(), &m);
+        assert_eq!(Matrix::scale(1.0, 0.0, 1.0).inverse().unwrap_err(), MathError::SingularMatrix);
+        assert!(matches!(Matrix::new(2, 3, None).inverse(), Err(MathError::NotSquare { rows: 2, columns: 3 })));
+    }
+
+    #[test]
+    fn translation_moves_points() {
+        let p = apply(&Matrix::translation(1.0, 2.0, -3.0), &Vector3::new(4.0, 5.0, 6.0));
+        assert!(p.equals(&Vector3::new(5.0, 7.0, 3.0)), "{:?}", p);
+    }
+
+    #[test]
+    fn look_at_maps_eye_to_origin_and_target_to_negative_z() {
+        let eye = Vector3::new(1.0, 2.0, 3.0);
+        let target = Vector3::new(1.0, 2.0, -7.0);
+        let view = Matrix::look_at(&eye, &target, &Vector3::new(0.0, 1.0, 0.0)).unwrap();
+        assert!(apply(&view, &eye).equals(&Vector3::zero()));
+        let t = apply(&view, &target);
+        assert!(t.x.abs() < 1e-9 && t.y.abs() < 1e-9 && (t.z + 10.0).abs() < 1e-9, "{:?}", t);
+    }
+
+    #[test]
+    fn look_at_rejects_degenerate_bases() {
+        let eye = Vector3::new(0.0, 64.0, 0.0);
+        let up = Vector3::new(0.0, 1.0, 0.0);
+        assert_eq!(Matrix::look_at(&eye, &eye, &up).unwrap_err(), MathError::ZeroLengthVector);
+        let above = Vector3::new(0.0, 70.0, 0.0);
+        assert_eq!(Matrix::look_at(&eye, &above, &up).unwrap_err(), MathError::ZeroLengthVector);
+        let ahead = Vector3::new(0.0, 64.0, -1.0);
+        assert_eq!(Matrix::look_at(&eye, &ahead, &Vector3::zero()).unwrap_err(), MathError::ZeroLengthVector);
+    }
+}
@@ -2,6 +2,7 @@
 
 pub mod axis;
 pub mod axis_aligned_bb;
+pub mod error;
 pub mod facing;
 pub mod math;
 pub mod matrix;
@@ -14,6 +15,7 @@ pub mod voxel_ray_trace;
 // Re-export commonly used types
 pub use axis::Axis;
 pub use axis_aligned_bb::AxisAlignedBB;
+pub use error::MathError;
 pub use facing::Facing;
 pub use math::Math; // Although Math struct is empty, keep the module
 pub use matrix::Matrix;
@@ -4,10 +4,14 @@
 
 use crate::math::{axis_aligned_bb::AxisAlignedBB, facing::Facing, vector3::Vector3};
 
+/// A ray hit on a bounding box. A miss is represented by `None` wherever a result is returned.
 #[derive(Debug, Clone, Copy)]
 pub struct RayTraceResult {
+    /// The box that was hit.
     pub bb: AxisAlignedBB,
+    /// The face of `bb` the ray entered through.
     pub hit_face: Facing,
+    /// The exact point on `hit_face` where the ray hit, in the same space as `bb`.
     pub hit_vector: Vector3,
 }
 
@@ -27,4 +31,16 @@ impl RayTraceResult {
     pub fn hit_vector(&self) -> &Vector3 {
         &self.hit_vector
     }
+
+    /// Distance from `origin` (normally the ray start) to the hit point.
+    pub fn distance_from(&self, origin: &Vector3) -> f64 {
+        self.hit_vector.distance(origin)
+    }
+
+    /// The hit closest to `origin`, e.g. when a ray was tested against several boxes.
+    pub fn nearest(hits: impl IntoIterator<Item = RayTraceResult>, origin: &Vector3) -> Option<RayTraceResult> {
+        hits.into_iter().min_by(|a, b| {
+            a.hit_vector.distance_squared(origin).total_cmp(&b.hit_vector.distance_squared(origin))
+        })
+    }
 }
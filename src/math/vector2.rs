@@ -2,7 +2,8 @@
 
 #![allow(dead_code)]
 
-use std::fmt;
+use crate::math::error::MathError;
+use std::{fmt, ops::{Add, AddAssign, Sub, SubAssign, Mul, Div, Neg}};
 
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct Vector2 {
@@ -15,6 +16,15 @@ impl Vector2 {
         Self { x, y }
     }
 
+    pub const fn zero() -> Self {
+        Self::new(0.0, 0.0)
+    }
+
+    /// Unit vector pointing at `radians`, measured counter-clockwise from +X.
+    pub fn from_angle(radians: f64) -> Vector2 {
+        Vector2::new(radians.cos(), radians.sin())
+    }
+
     pub const fn x(&self) -> f64 {
         self.x
     }
@@ -98,13 +108,138 @@ impl Vector2 {
         }
     }
 
+    /// Like `normalize`, but fails instead of returning the zero vector.
+    pub fn try_normalize(&self) -> Result<Vector2, MathError> {
+        let len_sq = self.length_squared();
+        if len_sq > 0.0 {
+            Ok(self.divide(len_sq.sqrt()))
+        } else {
+            Err(MathError::ZeroLengthVector)
+        }
+    }
+
     pub fn dot(&self, v: &Vector2) -> f64 {
         self.x * v.x + self.y * v.y
     }
+
+    /// Z component of the 3D cross product; positive when `v` is counter-clockwise from `self`.
+    pub fn cross(&self, v: &Vector2) -> f64 {
+        self.x * v.y - self.y * v.x
+    }
+
+    /// The vector rotated 90 degrees counter-clockwise.
+    pub fn perpendicular(&self) -> Vector2 {
+        Vector2::new(-self.y, self.x)
+    }
+
+    /// Signed angle in radians to rotate `self` onto `v`, in `(-PI, PI]`, positive
+    /// counter-clockwise. Returns 0 if either vector is zero.
+    pub fn angle_to(&self, v: &Vector2) -> f64 {
+        self.cross(v).atan2(self.dot(v))
+    }
 }
 
 impl fmt::Display for Vector2 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "Vector2(x={}, y={})", self.x, self.y)
     }
+}
+
+// --- Operator Overloads for convenience ---
+impl Add for Vector2 {
+    type Output = Self;
+    fn add(self, other: Self) -> Self {
+        self.add_vector(&other)
+    }
+}
+impl Sub for Vector2 {
+    type Output = Self;
+    fn sub(self, other: Self) -> Self {
+        self.subtract_vector(&other)
+    }
+}
+impl Mul<f64> for Vector2 {
+    type Output = Self;
+    fn mul(self, rhs: f64) -> Self {
+        self.multiply(rhs)
+    }
+}
+impl Mul<Vector2> for f64 {
+    type Output = Vector2;
+    fn mul(self, rhs: Vector2) -> Vector2 {
+        rhs.multiply(self)
+    }
+}
+impl Div<f64> for Vector2 {
+    type Output = Self;
+    fn div(self, rhs: f64) -> Self {
+        self.divide(rhs)
+    }
+}
+impl Neg for Vector2 {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Vector2::new(-self.x, -self.y)
+    }
+}
+impl AddAssign for Vector2 {
+    fn add_assign(&mut self, other: Self) {
+        *self = self.add_vector(&other);
+    }
+}
+impl SubAssign for Vector2 {
+    fn sub_assign(&mut self, other: Self) {
+        *self = self.subtract_vector(&other);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::f64::consts::{FRAC_PI_2, PI};
+
+    fn assert_close(a: Vector2, b: Vector2) {
+        assert!((a.x - b.x).abs() < 1e-9 && (a.y - b.y).abs() < 1e-9, "{} != {}", a, b);
+    }
+
+    #[test]
+    fn geometry_helpers() {
+        assert_close(Vector2::from_angle(FRAC_PI_2), Vector2::new(0.0, 1.0));
+        let x = Vector2::new(1.0, 0.0);
+        let y = Vector2::new(0.0, 1.0);
+        assert_eq!(x.cross(&y), 1.0);
+        assert_eq!(y.cross(&x), -1.0);
+        assert_eq!(x.perpendicular(), y);
+        assert!((x.angle_to(&y) - FRAC_PI_2).abs() < 1e-12);
+        assert!((y.angle_to(&x) + FRAC_PI_2).abs() < 1e-12);
+        assert!((x.angle_to(&Vector2::new(-1.0, 0.0)) - PI).abs() < 1e-12);
+        assert_eq!(Vector2::zero().angle_to(&x), 0.0);
+    }
+
+    #[test]
+    fn operators_match_methods() {
+        let a = Vector2::new(1.5, -2.0);
+        let b = Vector2::new(-3.0, 4.25);
+        assert_eq!(a + b, a.add_vector(&b));
+        assert_eq!(a - b, a.subtract_vector(&b));
+        assert_eq!(a * 3.0, a.multiply(3.0));
+        assert_eq!(3.0 * a, a.multiply(3.0));
+        assert_eq!(a / 2.0, a.divide(2.0));
+        assert_eq!(-a, a.multiply(-1.0));
+
+        let mut c = a;
+        c += b;
+        assert_eq!(c, a.add_vector(&b));
+        c -= b;
+        assert_eq!(c, a.add_vector(&b).subtract_vector(&b));
+    }
+
+    #[test]
+    fn try_normalize_near_zero() {
+        assert_eq!(Vector2::zero().try_normalize().unwrap_err(), MathError::ZeroLengthVector);
+        // The squared length underflows to zero
+        assert_eq!(Vector2::new(1e-200, 0.0).try_normalize().unwrap_err(), MathError::ZeroLengthVector);
+        assert_close(Vector2::new(3e-150, 4e-150).try_normalize().unwrap(), Vector2::new(0.6, 0.8));
+        assert_eq!(Vector2::new(0.0, -5.0).try_normalize().unwrap(), Vector2::new(0.0, -1.0));
+    }
 }
\ No newline at end of file
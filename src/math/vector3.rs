@@ -2,8 +2,8 @@
 
 #![allow(dead_code)]
 
-use crate::math::{vector2::Vector2, facing::Facing};
-use std::{fmt, ops::{Add, Sub, Mul, Div}};
+use crate::math::{vector2::Vector2, facing::Facing, axis::Axis, error::MathError};
+use std::{fmt, ops::{Add, AddAssign, Sub, SubAssign, Mul, Div, Neg}};
 
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct Vector3 {
@@ -90,10 +90,29 @@ impl Vector3 {
         )
     }
 
-    pub fn abs(&self) -> Vector3 {
+    pub const fn abs(&self) -> Vector3 {
         Vector3::new(self.x.abs(), self.y.abs(), self.z.abs())
     }
 
+    /// Sign of each component as -1.0 or 1.0 (see `f64::signum`; zero maps to 1.0).
+    pub const fn signum(&self) -> Vector3 {
+        Vector3::new(self.x.signum(), self.y.signum(), self.z.signum())
+    }
+
+    pub const fn min_component_wise(&self, other: &Vector3) -> Vector3 {
+        Vector3::new(self.x.min(other.x), self.y.min(other.y), self.z.min(other.z))
+    }
+
+    pub const fn max_component_wise(&self, other: &Vector3) -> Vector3 {
+        Vector3::new(self.x.max(other.x), self.y.max(other.y), self.z.max(other.z))
+    }
+
+    /// Confines each component to the matching range of `min` and `max`.
+    /// Panics if any component of `min` is greater than the one in `max`.
+    pub const fn clamp(&self, min: &Vector3, max: &Vector3) -> Vector3 {
+        Vector3::new(self.x.clamp(min.x, max.x), self.y.clamp(min.y, max.y), self.z.clamp(min.z, max.z))
+    }
+
     pub fn get_side(&self, side: Facing, step: i64) -> Vector3 {
         let offset = Facing::get_offset(side);
         self.add(
@@ -187,6 +206,16 @@ impl Vector3 {
         }
     }
 
+    /// Like `normalize`, but fails instead of returning the zero vector.
+    pub fn try_normalize(&self) -> Result<Vector3, MathError> {
+        let len_sq = self.length_squared();
+        if len_sq > 1e-10 {
+            Ok(self.divide(len_sq.sqrt()))
+        } else {
+            Err(MathError::ZeroLengthVector)
+        }
+    }
+
     pub fn dot(&self, v: &Vector3) -> f64 {
         self.x * v.x + self.y * v.y + self.z * v.z
     }
@@ -257,6 +286,22 @@ impl Vector3 {
         }
     }
 
+    pub const fn get_component(&self, axis: Axis) -> f64 {
+        match axis {
+            Axis::X => self.x,
+            Axis::Y => self.y,
+            Axis::Z => self.z,
+        }
+    }
+
+    pub const fn with_component(&self, axis: Axis, value: f64) -> Vector3 {
+        match axis {
+            Axis::X => Vector3::new(value, self.y, self.z),
+            Axis::Y => Vector3::new(self.x, value, self.z),
+            Axis::Z => Vector3::new(self.x, self.y, value),
+        }
+    }
+
     pub fn with_components(&self, x: Option<f64>, y: Option<f64>, z: Option<f64>) -> Vector3 {
         Vector3::new(
             x.unwrap_or(self.x),
@@ -333,9 +378,64 @@ impl Mul<f64> for Vector3 {
         self.multiply(rhs)
     }
 }
+impl Mul<Vector3> for f64 {
+    type Output = Vector3;
+    fn mul(self, rhs: Vector3) -> Vector3 {
+        rhs.multiply(self)
+    }
+}
 impl Div<f64> for Vector3 {
     type Output = Self;
     fn div(self, rhs: f64) -> Self {
         self.divide(rhs)
     }
 }
+impl Neg for Vector3 {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Vector3::new(-self.x, -self.y, -self.z)
+    }
+}
+impl AddAssign for Vector3 {
+    fn add_assign(&mut self, other: Self) {
+        *self = self.add_vector(&other);
+    }
+}
+impl SubAssign for Vector3 {
+    fn sub_assign(&mut self, other: Self) {
+        *self = self.subtract_vector(&other);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn operators_match_methods() {
+        let a = Vector3::new(1.5, -2.0, 0.25);
+        let b = Vector3::new(-3.0, 4.25, 8.0);
+        assert_eq!(a + b, a.add_vector(&b));
+        assert_eq!(a - b, a.subtract_vector(&b));
+        assert_eq!(a * 3.0, a.multiply(3.0));
+        assert_eq!(3.0 * a, a.multiply(3.0));
+        assert_eq!(a / 2.0, a.divide(2.0));
+        assert_eq!(-a, a.multiply(-1.0));
+
+        let mut c = a;
+        c += b;
+        assert_eq!(c, a.add_vector(&b));
+        c -= b;
+        assert_eq!(c, a.add_vector(&b).subtract_vector(&b));
+    }
+
+    #[test]
+    fn try_normalize_near_zero() {
+        assert_eq!(Vector3::zero().try_normalize().unwrap_err(), MathError::ZeroLengthVector);
+        // Lengths below 1e-5 count as zero, matching normalize
+        assert_eq!(Vector3::new(1e-6, 0.0, 0.0).try_normalize().unwrap_err(), MathError::ZeroLengthVector);
+        assert_eq!(Vector3::new(1e-6, 0.0, 0.0).normalize(), Vector3::zero());
+        let unit = Vector3::new(0.0, 2e-5, 0.0).try_normalize().unwrap();
+        assert!((unit.y - 1.0).abs() < 1e-12 && unit.x == 0.0 && unit.z == 0.0, "{}", unit);
+    }
+}
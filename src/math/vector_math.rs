@@ -2,12 +2,133 @@
 
 #![allow(dead_code)]
 
-use crate::math::vector2::Vector2;
+use crate::math::{vector2::Vector2, vector3::Vector3};
 
 pub struct VectorMath;
 
+/// Bit widths used by `VectorMath::hash_block_pos`.
+const BLOCK_HASH_XZ_BITS: u32 = 27;
+const BLOCK_HASH_Y_BITS: u32 = 10;
+
 impl VectorMath {
     pub fn get_direction2d(azimuth_radians: f64) -> Vector2 {
         Vector2::new(azimuth_radians.cos(), azimuth_radians.sin())
     }
+
+    /// Unit look vector for a Minecraft yaw/pitch in degrees. Yaw 0 faces south (+Z) and
+    /// increases clockwise seen from above (90 = west); positive pitch looks down.
+    pub fn get_direction_vector(yaw_deg: f64, pitch_deg: f64) -> Vector3 {
+        let yaw = yaw_deg.to_radians();
+        let pitch = pitch_deg.to_radians();
+        let horizontal = pitch.cos();
+        Vector3::new(-yaw.sin() * horizontal, -pitch.sin(), yaw.cos() * horizontal)
+    }
+
+    /// Inverse of `get_direction_vector`: returns `(yaw, pitch)` in degrees, with yaw in
+    /// `[0, 360)`. The vector does not need to be normalized; a zero vector yields `(0, 0)`.
+    pub fn get_rotation(dir: &Vector3) -> (f64, f64) {
+        let horizontal = (dir.x * dir.x + dir.z * dir.z).sqrt();
+        let yaw = (-dir.x).atan2(dir.z).to_degrees().rem_euclid(360.0);
+        let pitch = (-dir.y).atan2(horizontal).to_degrees();
+        (yaw, pitch)
+    }
+
+    /// Packs a block position into a single `u64` for use as a hash map key.
+    ///
+    /// Layout, most significant bit first: X in bits 37..64, Y in bits 27..37, Z in bits 0..27.
+    /// Each field holds the two's complement value truncated to its width, so X and Z must be in
+    /// `[-2^26, 2^26)` (about +/-67 million, past the world border) and Y in `[-512, 512)`.
+    /// Values outside those ranges wrap and collide with other positions.
+    pub fn hash_block_pos(x: i32, y: i32, z: i32) -> u64 {
+        debug_assert!(Self::fits(x, BLOCK_HASH_XZ_BITS), "block X {} out of hashable range", x);
+        debug_assert!(Self::fits(y, BLOCK_HASH_Y_BITS), "block Y {} out of hashable range", y);
+        debug_assert!(Self::fits(z, BLOCK_HASH_XZ_BITS), "block Z {} out of hashable range", z);
+        let xz_mask = (1u64 << BLOCK_HASH_XZ_BITS) - 1;
+        let y_mask = (1u64 << BLOCK_HASH_Y_BITS) - 1;
+        ((x as u64 & xz_mask) << (BLOCK_HASH_XZ_BITS + BLOCK_HASH_Y_BITS))
+            | ((y as u64 & y_mask) << BLOCK_HASH_XZ_BITS)
+            | (z as u64 & xz_mask)
+    }
+
+    /// Inverse of `hash_block_pos`, returning `(x, y, z)`.
+    pub fn unhash_block_pos(hash: u64) -> (i32, i32, i32) {
+        let x = Self::sign_extend(hash >> (BLOCK_HASH_XZ_BITS + BLOCK_HASH_Y_BITS), BLOCK_HASH_XZ_BITS);
+        let y = Self::sign_extend(hash >> BLOCK_HASH_XZ_BITS, BLOCK_HASH_Y_BITS);
+        let z = Self::sign_extend(hash, BLOCK_HASH_XZ_BITS);
+        (x, y, z)
+    }
+
+    /// Packs chunk coordinates into a `u64`: X in the high 32 bits, Z in the low 32 bits.
+    /// Every `i32` pair maps to a distinct value.
+    pub fn chunk_hash(cx: i32, cz: i32) -> u64 {
+        ((cx as u32 as u64) << 32) | (cz as u32 as u64)
+    }
+
+    /// Inverse of `chunk_hash`, returning `(cx, cz)`.
+    pub fn unhash_chunk(hash: u64) -> (i32, i32) {
+        ((hash >> 32) as u32 as i32, hash as u32 as i32)
+    }
+
+    fn fits(value: i32, bits: u32) -> bool {
+        let half = 1i64 << (bits - 1);
+        (-half..half).contains(&(value as i64))
+    }
+
+    fn sign_extend(value: u64, bits: u32) -> i32 {
+        let shift = 64 - bits;
+        ((value << shift) as i64 >> shift) as i32
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(a: f64, b: f64) {
+        assert!((a - b).abs() < 1e-9, "{} != {}", a, b);
+    }
+
+    #[test]
+    fn direction_vector_matches_minecraft_axes() {
+        let south = VectorMath::get_direction_vector(0.0, 0.0);
+        assert_close(south.z, 1.0);
+        let west = VectorMath::get_direction_vector(90.0, 0.0);
+        assert_close(west.x, -1.0);
+        let down = VectorMath::get_direction_vector(0.0, 90.0);
+        assert_close(down.y, -1.0);
+    }
+
+    #[test]
+    fn rotation_round_trips_through_direction_vector() {
+        for (yaw, pitch) in [(0.0, 0.0), (45.0, -30.0), (180.0, 60.0), (270.0, 10.0), (359.5, -89.0)] {
+            let (y, p) = VectorMath::get_rotation(&VectorMath::get_direction_vector(yaw, pitch));
+            assert_close(y, yaw);
+            assert_close(p, pitch);
+        }
+        // Negative yaw comes back in [0, 360)
+        let (y, _) = VectorMath::get_rotation(&VectorMath::get_direction_vector(-90.0, 0.0));
+        assert_close(y, 270.0);
+        assert_eq!(VectorMath::get_rotation(&Vector3::zero()), (0.0, 0.0));
+    }
+
+    #[test]
+    fn block_hash_round_trips_at_negative_coordinates() {
+        let limit = 1 << 26;
+        let positions = [
+            (0, 0, 0), (-1, -1, -1), (-30_000_000, -64, 29_999_999), (-limit, -512, limit - 1), (limit - 1, 511, -limit),
+        ];
+        for (x, y, z) in positions {
+            assert_eq!(VectorMath::unhash_block_pos(VectorMath::hash_block_pos(x, y, z)), (x, y, z));
+        }
+        assert_ne!(VectorMath::hash_block_pos(-1, 0, 0), VectorMath::hash_block_pos(0, 0, -1));
+        assert_ne!(VectorMath::hash_block_pos(0, -1, 0), VectorMath::hash_block_pos(0, 0, -1));
+    }
+
+    #[test]
+    fn chunk_hash_round_trips_at_negative_coordinates() {
+        for (cx, cz) in [(0, 0), (-1, 0), (0, -1), (-1, -1), (i32::MIN, i32::MAX), (i32::MAX, i32::MIN)] {
+            assert_eq!(VectorMath::unhash_chunk(VectorMath::chunk_hash(cx, cz)), (cx, cz));
+        }
+        assert_ne!(VectorMath::chunk_hash(-1, 0), VectorMath::chunk_hash(0, -1));
+    }
 }
\ No newline at end of file
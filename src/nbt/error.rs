@@ -5,16 +5,28 @@ use std::error::Error;
 use std::fmt;
 use crate::utils; // Adjusted path
 
+/// Errors raised while reading, writing or manipulating NBT.
+///
+/// Decoding failures are split by cause so callers can react differently, e.g. wait for more
+/// data on `UnexpectedEof` but reject the payload on `InvalidTagType` or a limit error.
 #[derive(Debug)]
 pub enum NbtError {
-    IoError(utils::error::BinaryDataException), // Use qualified path
+    /// Malformed binary input other than truncation.
+    BinaryData(utils::error::BinaryDataException),
+    Io(std::io::Error),
+    /// The input ended before the value being read was complete.
+    UnexpectedEof(String),
+    /// A tag type ID outside the known range was encountered.
+    InvalidTagType(u8),
     DataError(String),
     InvalidTagValue(String),
     UnexpectedTagType(String),
     NoSuchTag(String),
     DepthLimitExceeded(String),
+    /// A `ReaderLimits` bound was exceeded.
+    SizeLimitExceeded(String),
     InvalidOperation(String),
-    Utf8Error(std::string::FromUtf8Error),
+    InvalidUtf8(std::string::FromUtf8Error),
     TryFromIntError(std::num::TryFromIntError),
 }
 
@@ -37,19 +49,29 @@ impl NbtError {
     pub fn new_invalid_operation(message: &str) -> Self {
         NbtError::InvalidOperation(message.to_string())
     }
+    pub fn new_unexpected_eof(message: &str) -> Self {
+        NbtError::UnexpectedEof(message.to_string())
+    }
+    pub fn new_size_limit_exceeded(message: &str) -> Self {
+        NbtError::SizeLimitExceeded(message.to_string())
+    }
 }
 
 impl fmt::Display for NbtError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
-            NbtError::IoError(e) => write!(f, "NBT IO Error: {}", e),
+            NbtError::BinaryData(e) => write!(f, "NBT Binary Data Error: {}", e),
+            NbtError::Io(e) => write!(f, "NBT IO Error: {}", e),
+            NbtError::UnexpectedEof(msg) => write!(f, "NBT Unexpected End of Input: {}", msg),
+            NbtError::InvalidTagType(id) => write!(f, "NBT Invalid Tag Type: unknown tag type ID {}", id),
             NbtError::DataError(msg) => write!(f, "NBT Data Error: {}", msg),
             NbtError::InvalidTagValue(msg) => write!(f, "NBT Invalid Tag Value: {}", msg),
             NbtError::UnexpectedTagType(msg) => write!(f, "NBT Unexpected Tag Type: {}", msg),
             NbtError::NoSuchTag(msg) => write!(f, "NBT No Such Tag: {}", msg),
             NbtError::DepthLimitExceeded(msg) => write!(f, "NBT Depth Limit Exceeded: {}", msg),
+            NbtError::SizeLimitExceeded(msg) => write!(f, "NBT Size Limit Exceeded: {}", msg),
             NbtError::InvalidOperation(msg) => write!(f, "NBT Invalid Operation: {}", msg),
-            NbtError::Utf8Error(e) => write!(f, "NBT UTF-8 Error: {}", e),
+            NbtError::InvalidUtf8(e) => write!(f, "NBT Invalid UTF-8: {}", e),
             NbtError::TryFromIntError(e) => write!(f, "NBT Integer Conversion Error: {}", e),
         }
     }
@@ -58,8 +80,9 @@ impl fmt::Display for NbtError {
 impl Error for NbtError {
     fn source(&self) -> Option<&(dyn Error + 'static)> {
         match self {
-            NbtError::IoError(e) => Some(e),
-            NbtError::Utf8Error(e) => Some(e),
+            NbtError::BinaryData(e) => Some(e),
+            NbtError::Io(e) => Some(e),
+            NbtError::InvalidUtf8(e) => Some(e),
             NbtError::TryFromIntError(e) => Some(e),
             _ => None,
         }
@@ -68,13 +91,27 @@ impl Error for NbtError {
 
 impl From<utils::error::BinaryDataException> for NbtError {
     fn from(err: utils::error::BinaryDataException) -> Self {
-        NbtError::IoError(err)
+        if err.is_eof() {
+            NbtError::UnexpectedEof(err.to_string())
+        } else {
+            NbtError::BinaryData(err)
+        }
+    }
+}
+
+impl From<std::io::Error> for NbtError {
+    fn from(err: std::io::Error) -> Self {
+        if err.kind() == std::io::ErrorKind::UnexpectedEof {
+            NbtError::UnexpectedEof(err.to_string())
+        } else {
+            NbtError::Io(err)
+        }
     }
 }
 
 impl From<std::string::FromUtf8Error> for NbtError {
     fn from(err: std::string::FromUtf8Error) -> Self {
-        NbtError::Utf8Error(err)
+        NbtError::InvalidUtf8(err)
     }
 }
 
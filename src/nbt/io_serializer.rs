@@ -0,0 +1,225 @@
+// src/nbt/io_serializer.rs
+#![allow(dead_code)]
+
+//! NBT encoding over `std::io::Read` / `std::io::Write`, for streaming large trees to or from a
+//! file or socket without holding the whole encoding in memory. The byte order is chosen with a
+//! `byteorder` type parameter; `BigEndianNbtSerializer::write_to`/`read_from` and their
+//! little-endian counterparts are the usual entry points.
+//!
+//! Wrap unbuffered sinks and sources in `BufWriter`/`BufReader`: every field is a separate call.
+
+use crate::nbt::error::{NbtError, Result};
+use crate::nbt::reader_tracker::{ReaderLimits, ReaderTracker};
+use crate::nbt::serializer::{NbtRead, NbtWrite};
+use crate::nbt::tag::{self, TagType};
+use crate::nbt::tree_root::TreeRoot;
+use crate::utils::limits;
+use byteorder::{ByteOrder, ReadBytesExt, WriteBytesExt};
+use std::io::{Read, Write};
+use std::marker::PhantomData;
+
+// Upper bound on elements reserved ahead of reading an IntArray, since the declared length of a
+// streamed array cannot be checked against the remaining input
+const MAX_INT_ARRAY_PREALLOC: usize = 4096;
+
+pub struct IoNbtReader<'a, R: Read, E: ByteOrder> {
+    reader: &'a mut R,
+    _order: PhantomData<E>,
+}
+
+impl<'a, R: Read, E: ByteOrder> IoNbtReader<'a, R, E> {
+    pub fn new(reader: &'a mut R) -> Self {
+        Self { reader, _order: PhantomData }
+    }
+
+    /// Reads one named root tag. Stops right after it, so several roots can be read in turn.
+    pub fn read_root(&mut self, max_depth: usize, limits: ReaderLimits) -> Result<TreeRoot> {
+        let type_id = self.read_byte()?;
+        if type_id == TagType::End as u8 {
+            return Err(NbtError::new_data_error("Found TAG_End at the start of stream"));
+        }
+        let tag_type = TagType::from_id(type_id).ok_or(NbtError::InvalidTagType(type_id))?;
+        let mut tracker = ReaderTracker::with_limits(max_depth, limits);
+        let root_name = self.read_string(&mut tracker)?;
+        let root_tag = tag::create_tag(tag_type, self, &mut tracker)?;
+        TreeRoot::new(root_name, root_tag)
+    }
+
+    fn read_length(&mut self, what: &str) -> Result<usize> {
+        let length = self.read_int()?;
+        usize::try_from(length)
+            .map_err(|_| NbtError::new_data_error(&format!("{} length cannot be less than zero ({})", what, length)))
+    }
+}
+
+impl<R: Read, E: ByteOrder> NbtRead for IoNbtReader<'_, R, E> {
+    fn read_byte(&mut self) -> Result<u8> { Ok(self.reader.read_u8()?) }
+    fn read_signed_byte(&mut self) -> Result<i8> { Ok(self.reader.read_i8()?) }
+    fn read_short(&mut self) -> Result<i16> { Ok(self.reader.read_i16::<E>()?) }
+    fn read_signed_short(&mut self) -> Result<i16> { Ok(self.reader.read_i16::<E>()?) }
+    fn read_int(&mut self) -> Result<i32> { Ok(self.reader.read_i32::<E>()?) }
+    fn read_long(&mut self) -> Result<i64> { Ok(self.reader.read_i64::<E>()?) }
+    fn read_float(&mut self) -> Result<f32> { Ok(self.reader.read_f32::<E>()?) }
+    fn read_double(&mut self) -> Result<f64> { Ok(self.reader.read_f64::<E>()?) }
+
+    fn read_byte_array(&mut self, tracker: &mut ReaderTracker) -> Result<Vec<u8>> {
+        let length = self.read_length("ByteArray")?;
+        tracker.consume_bytes(length)?;
+        // Grow with the data actually received rather than trusting the declared length
+        let mut bytes = Vec::new();
+        (&mut *self.reader).take(length as u64).read_to_end(&mut bytes)?;
+        if bytes.len() < length {
+            return Err(NbtError::new_unexpected_eof(&format!(
+                "ByteArray declared {} bytes but the stream ended after {}", length, bytes.len()
+            )));
+        }
+        Ok(bytes)
+    }
+
+    fn read_string(&mut self, tracker: &mut ReaderTracker) -> Result<String> {
+        let length = self.read_short()?;
+        if length < 0 {
+            return Err(NbtError::new_data_error(&format!("NBT string length cannot be negative ({})", length)));
+        }
+        tracker.consume_bytes(length as usize)?;
+        let mut bytes = vec![0u8; length as usize];
+        self.reader.read_exact(&mut bytes)?;
+        String::from_utf8(bytes).map_err(NbtError::from)
+    }
+
+    fn read_int_array(&mut self, tracker: &mut ReaderTracker) -> Result<Vec<i32>> {
+        let length = self.read_length("IntArray")?;
+        tracker.consume_bytes(length.saturating_mul(std::mem::size_of::<i32>()))?;
+        let mut result = Vec::with_capacity(length.min(MAX_INT_ARRAY_PREALLOC));
+        for _ in 0..length {
+            result.push(self.read_int()?);
+        }
+        Ok(result)
+    }
+}
+
+pub struct IoNbtWriter<'a, W: Write, E: ByteOrder> {
+    writer: &'a mut W,
+    _order: PhantomData<E>,
+}
+
+impl<'a, W: Write, E: ByteOrder> IoNbtWriter<'a, W, E> {
+    pub fn new(writer: &'a mut W) -> Self {
+        Self { writer, _order: PhantomData }
+    }
+
+    /// Writes one named root tag. Does not flush the underlying writer.
+    pub fn write_root(&mut self, root: &TreeRoot) -> Result<()> {
+        self.write_byte(root.get_tag().get_type() as u8)?;
+        self.write_string(root.get_name())?;
+        root.get_tag().write(self)
+    }
+
+    fn write_length(&mut self, len: usize, what: &str) -> Result<()> {
+        let len: i32 = len.try_into()
+            .map_err(|_| NbtError::new_invalid_tag_value(&format!("{} length too large for i32", what)))?;
+        self.write_int(len)
+    }
+}
+
+impl<W: Write, E: ByteOrder> NbtWrite for IoNbtWriter<'_, W, E> {
+    fn write_byte(&mut self, v: u8) -> Result<()> { Ok(self.writer.write_u8(v)?) }
+    fn write_signed_byte(&mut self, v: i8) -> Result<()> { Ok(self.writer.write_i8(v)?) }
+    fn write_short(&mut self, v: i16) -> Result<()> { Ok(self.writer.write_i16::<E>(v)?) }
+    fn write_int(&mut self, v: i32) -> Result<()> { Ok(self.writer.write_i32::<E>(v)?) }
+    fn write_long(&mut self, v: i64) -> Result<()> { Ok(self.writer.write_i64::<E>(v)?) }
+    fn write_float(&mut self, v: f32) -> Result<()> { Ok(self.writer.write_f32::<E>(v)?) }
+    fn write_double(&mut self, v: f64) -> Result<()> { Ok(self.writer.write_f64::<E>(v)?) }
+
+    fn write_byte_array(&mut self, v: &[u8]) -> Result<()> {
+        self.write_length(v.len(), "ByteArray")?;
+        Ok(self.writer.write_all(v)?)
+    }
+
+    fn write_string(&mut self, v: &str) -> Result<()> {
+        if v.len() > limits::I16_MAX as usize {
+            return Err(NbtError::new_invalid_tag_value(&format!(
+                "NBT string length too large ({} > {})", v.len(), limits::I16_MAX
+            )));
+        }
+        self.write_short(v.len() as i16)?;
+        Ok(self.writer.write_all(v.as_bytes())?)
+    }
+
+    fn write_int_array(&mut self, v: &[i32]) -> Result<()> {
+        self.write_length(v.len(), "IntArray")?;
+        for &val in v {
+            self.write_int(val)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nbt::big_endian_serializer::BigEndianNbtSerializer;
+    use crate::nbt::little_endian_serializer::LittleEndianNbtSerializer;
+    use crate::nbt::tag::CompoundTag;
+    use std::io::{self, Cursor};
+
+    fn limits(max_total_bytes: usize) -> ReaderLimits {
+        ReaderLimits { max_total_bytes, ..ReaderLimits::default() }
+    }
+
+    // Root tag of `type_id` with an empty name and a big-endian i32 length prefix, followed by an
+    // endless source of bytes so only the budget can stop the read
+    fn oversized_root(type_id: u8, declared: i32) -> impl Read {
+        let mut header = vec![type_id, 0, 0];
+        header.extend_from_slice(&declared.to_be_bytes());
+        Cursor::new(header).chain(io::repeat(0))
+    }
+
+    #[test]
+    fn oversized_byte_array_prefix_is_rejected_before_reading() {
+        let mut reader = oversized_root(TagType::ByteArray as u8, i32::MAX);
+        let err = BigEndianNbtSerializer::read_from_with_limits(&mut reader, 0, limits(1 << 20)).unwrap_err();
+        assert!(matches!(err, NbtError::SizeLimitExceeded(_)), "{:?}", err);
+    }
+
+    #[test]
+    fn oversized_int_array_prefix_is_rejected_before_reading() {
+        let mut reader = oversized_root(TagType::IntArray as u8, i32::MAX);
+        let err = BigEndianNbtSerializer::read_from_with_limits(&mut reader, 0, limits(1 << 20)).unwrap_err();
+        assert!(matches!(err, NbtError::SizeLimitExceeded(_)), "{:?}", err);
+    }
+
+    #[test]
+    fn oversized_string_prefix_is_rejected_before_reading() {
+        let mut reader = Cursor::new(vec![TagType::String as u8, 0, 0, 0x7F, 0xFF]).chain(io::repeat(b'a'));
+        let err = BigEndianNbtSerializer::read_from_with_limits(&mut reader, 0, limits(1024)).unwrap_err();
+        assert!(matches!(err, NbtError::SizeLimitExceeded(_)), "{:?}", err);
+    }
+
+    #[test]
+    fn truncated_byte_array_without_limit_is_unexpected_eof() {
+        let mut data = vec![TagType::ByteArray as u8, 0, 0];
+        data.extend_from_slice(&i32::MAX.to_be_bytes());
+        data.extend_from_slice(&[1, 2, 3]);
+        let err = BigEndianNbtSerializer::read_from_with_limits(&mut Cursor::new(data), 0, ReaderLimits::unlimited())
+            .unwrap_err();
+        assert!(matches!(err, NbtError::UnexpectedEof(_)), "{:?}", err);
+    }
+
+    #[test]
+    fn streamed_round_trip_matches_buffered_encoding() {
+        let mut compound = CompoundTag::new();
+        compound.set_string("name".to_string(), "stream".to_string()).unwrap();
+        compound.set_int_array("ints".to_string(), vec![1, -2, 3]).unwrap();
+        let root = TreeRoot::new("root".to_string(), Box::new(compound)).unwrap();
+
+        let mut streamed = Vec::new();
+        LittleEndianNbtSerializer::write_to(&mut streamed, &root).unwrap();
+        assert_eq!(streamed, LittleEndianNbtSerializer::write_to_bytes(&root).unwrap());
+        let mut cursor = Cursor::new(&streamed);
+        let decoded = LittleEndianNbtSerializer::read_from(&mut cursor, 0).unwrap();
+        assert_eq!(cursor.position() as usize, streamed.len());
+        assert_eq!(decoded.get_name(), "root");
+        assert!(decoded.get_tag().equals(root.get_tag()));
+    }
+}
@@ -0,0 +1,258 @@
+// src/nbt/json.rs
+#![allow(dead_code)]
+
+//! Conversion between NBT trees and `serde_json::Value`.
+//!
+//! JSON has a single number type, so the exact tag types are recorded next to the data: every
+//! JSON object produced from a `CompoundTag` carries a `"__type"` object mapping each key to its
+//! tag type name (`"Byte"`, `"IntArray"`, ...). Lists are described as `"List:<element>"`, e.g.
+//! `"List:Double"`. A list of lists is described by an array with one descriptor per element,
+//! e.g. `["List:Int", "List:String"]`, since its elements may have different types. With
+//! `TypeHints::Embedded`, `from_json` uses those descriptors and the round trip is lossless for
+//! finite values.
+//!
+//! Lossy cases:
+//! - NaN and infinite floats have no JSON representation and become `null`, which cannot be
+//!   read back.
+//! - Without hints (`TypeHints::Infer`, or a key missing from `"__type"`), integers become
+//!   `Int` if they fit and `Long` otherwise, all other numbers become `Double`, booleans become
+//!   `Byte`, and arrays become lists, so byte and int arrays turn into lists of `Int`.
+//! - A compound key literally named `"__type"` is reserved and cannot be represented.
+
+use crate::nbt::error::{NbtError, Result};
+use crate::nbt::tag::{
+    ByteTag, ShortTag, IntTag, LongTag, FloatTag, DoubleTag,
+    ByteArrayTag, StringTag, ListTag, CompoundTag, IntArrayTag, Tag, TagType
+};
+use serde_json::{Map, Number, Value};
+
+pub const TYPE_KEY: &str = "__type";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TypeHints {
+    /// Use the `"__type"` descriptors written by `to_json`, inferring only where one is missing.
+    Embedded,
+    /// Ignore any descriptors and infer tag types from the JSON values alone.
+    Infer,
+}
+
+pub fn to_json(tag: &dyn Tag) -> Value {
+    let any = tag.as_any();
+    match tag.get_type() {
+        TagType::End => Value::Null,
+        TagType::Byte => any.downcast_ref::<ByteTag>().map_or(Value::Null, |t| Value::from(t.value)),
+        TagType::Short => any.downcast_ref::<ShortTag>().map_or(Value::Null, |t| Value::from(t.value)),
+        TagType::Int => any.downcast_ref::<IntTag>().map_or(Value::Null, |t| Value::from(t.value)),
+        TagType::Long => any.downcast_ref::<LongTag>().map_or(Value::Null, |t| Value::from(t.value)),
+        TagType::Float => any.downcast_ref::<FloatTag>().map_or(Value::Null, |t| float_to_json(t.value as f64)),
+        TagType::Double => any.downcast_ref::<DoubleTag>().map_or(Value::Null, |t| float_to_json(t.value)),
+        TagType::ByteArray => any.downcast_ref::<ByteArrayTag>()
+            .map_or(Value::Null, |t| Value::Array(t.value.iter().map(|&b| Value::from(b as i8)).collect())),
+        TagType::String => any.downcast_ref::<StringTag>().map_or(Value::Null, |t| Value::from(t.value.as_str())),
+        TagType::IntArray => any.downcast_ref::<IntArrayTag>()
+            .map_or(Value::Null, |t| Value::Array(t.value.iter().map(|&i| Value::from(i)).collect())),
+        TagType::List => any.downcast_ref::<ListTag>()
+            .map_or(Value::Null, |t| Value::Array(t.iter().map(to_json).collect())),
+        TagType::Compound => any.downcast_ref::<CompoundTag>().map_or(Value::Null, |t| {
+            let mut object = Map::new();
+            let mut types = Map::new();
+            for (name, child) in t.iter() {
+                types.insert(name.clone(), type_descriptor(child));
+                object.insert(name.clone(), to_json(child));
+            }
+            object.insert(TYPE_KEY.to_string(), Value::Object(types));
+            Value::Object(object)
+        }),
+    }
+}
+
+pub fn from_json(value: &Value, hints: TypeHints) -> Result<Box<dyn Tag>> {
+    match value {
+        Value::Object(object) => Ok(Box::new(compound_from_json(object, hints)?)),
+        _ => infer(value, hints),
+    }
+}
+
+fn float_to_json(value: f64) -> Value {
+    Number::from_f64(value).map_or(Value::Null, Value::Number)
+}
+
+fn type_descriptor(tag: &dyn Tag) -> Value {
+    match tag.as_any().downcast_ref::<ListTag>() {
+        Some(list) if list.get_tag_type() == TagType::List => Value::Array(list.iter().map(type_descriptor).collect()),
+        Some(list) => Value::from(format!("{}:{}", TagType::List.get_name(), list.get_tag_type().get_name())),
+        None => Value::from(tag.get_type().get_name()),
+    }
+}
+
+fn tag_type_from_name(name: &str) -> Option<TagType> {
+    (0..=11).filter_map(TagType::from_id).find(|t| t.get_name() == name)
+}
+
+fn compound_from_json(object: &Map<String, Value>, hints: TypeHints) -> Result<CompoundTag> {
+    let types = match (hints, object.get(TYPE_KEY)) {
+        (TypeHints::Embedded, Some(Value::Object(types))) => Some(types),
+        _ => None,
+    };
+    let mut compound = CompoundTag::new();
+    for (name, child) in object {
+        if name == TYPE_KEY {
+            continue;
+        }
+        let tag = match types.and_then(|t| t.get(name)) {
+            Some(Value::String(descriptor)) => typed(child, descriptor, hints)?,
+            Some(Value::Array(descriptors)) => list_of_lists(child, descriptors, hints)?,
+            _ => infer(child, hints)?,
+        };
+        compound.set_tag(name.clone(), tag)?;
+    }
+    Ok(compound)
+}
+
+fn typed(value: &Value, descriptor: &str, hints: TypeHints) -> Result<Box<dyn Tag>> {
+    let (head, rest) = match descriptor.split_once(':') {
+        Some((head, rest)) => (head, Some(rest)),
+        None => (descriptor, None),
+    };
+    let tag_type = tag_type_from_name(head)
+        .ok_or_else(|| NbtError::new_data_error(&format!("Unknown tag type \"{}\" in {}", head, TYPE_KEY)))?;
+    let mismatch = || NbtError::new_unexpected_tag_type(&format!("JSON value {} is not a valid {}", value, descriptor));
+
+    let tag: Box<dyn Tag> = match tag_type {
+        TagType::Byte => Box::new(ByteTag::new(value.as_i64().and_then(|v| i8::try_from(v).ok()).ok_or_else(mismatch)?)),
+        TagType::Short => Box::new(ShortTag::new(value.as_i64().and_then(|v| i16::try_from(v).ok()).ok_or_else(mismatch)?)),
+        TagType::Int => Box::new(IntTag::new(value.as_i64().and_then(|v| i32::try_from(v).ok()).ok_or_else(mismatch)?)),
+        TagType::Long => Box::new(LongTag::new(value.as_i64().ok_or_else(mismatch)?)),
+        TagType::Float => Box::new(FloatTag::new(value.as_f64().ok_or_else(mismatch)? as f32)),
+        TagType::Double => Box::new(DoubleTag::new(value.as_f64().ok_or_else(mismatch)?)),
+        TagType::String => Box::new(StringTag::new(value.as_str().ok_or_else(mismatch)?.to_string())),
+        TagType::ByteArray => {
+            let items = value.as_array().ok_or_else(mismatch)?;
+            let bytes = items.iter()
+                .map(|v| v.as_i64().and_then(|v| i8::try_from(v).ok()).map(|b| b as u8))
+                .collect::<Option<Vec<u8>>>()
+                .ok_or_else(mismatch)?;
+            Box::new(ByteArrayTag::new(bytes))
+        }
+        TagType::IntArray => {
+            let items = value.as_array().ok_or_else(mismatch)?;
+            let ints = items.iter()
+                .map(|v| v.as_i64().and_then(|v| i32::try_from(v).ok()))
+                .collect::<Option<Vec<i32>>>()
+                .ok_or_else(mismatch)?;
+            Box::new(IntArrayTag::new(ints))
+        }
+        TagType::List => {
+            let items = value.as_array().ok_or_else(mismatch)?;
+            let element_type = rest.and_then(|r| tag_type_from_name(r.split(':').next().unwrap_or(r)));
+            let mut list = ListTag::new(element_type.unwrap_or(TagType::End));
+            for item in items {
+                let element = match rest {
+                    Some(rest) => typed(item, rest, hints)?,
+                    None => infer(item, hints)?,
+                };
+                list.push(element)?;
+            }
+            Box::new(list)
+        }
+        TagType::Compound => Box::new(compound_from_json(value.as_object().ok_or_else(mismatch)?, hints)?),
+        TagType::End => return Err(NbtError::new_data_error("Cannot create TagType::End from JSON")),
+    };
+    Ok(tag)
+}
+
+// A list of lists, typed by one descriptor per element as written by `type_descriptor`
+fn list_of_lists(value: &Value, descriptors: &[Value], hints: TypeHints) -> Result<Box<dyn Tag>> {
+    let items = value.as_array()
+        .filter(|items| items.len() == descriptors.len())
+        .ok_or_else(|| NbtError::new_unexpected_tag_type(&format!(
+            "JSON value {} does not match its {} list descriptors", value, descriptors.len()
+        )))?;
+    let mut list = ListTag::new(TagType::List);
+    for (item, descriptor) in items.iter().zip(descriptors) {
+        let element = match descriptor {
+            Value::String(descriptor) => typed(item, descriptor, hints)?,
+            Value::Array(descriptors) => list_of_lists(item, descriptors, hints)?,
+            other => return Err(NbtError::new_data_error(&format!("Invalid list descriptor {} in {}", other, TYPE_KEY))),
+        };
+        list.push(element)?;
+    }
+    Ok(Box::new(list))
+}
+
+fn infer(value: &Value, hints: TypeHints) -> Result<Box<dyn Tag>> {
+    let tag: Box<dyn Tag> = match value {
+        Value::Null => return Err(NbtError::new_data_error("JSON null has no NBT equivalent")),
+        Value::Bool(b) => Box::new(ByteTag::new(*b as i8)),
+        Value::Number(n) => match n.as_i64() {
+            Some(v) => match i32::try_from(v) {
+                Ok(v) => Box::new(IntTag::new(v)),
+                Err(_) => Box::new(LongTag::new(v)),
+            },
+            None => Box::new(DoubleTag::new(n.as_f64().ok_or_else(|| {
+                NbtError::new_invalid_tag_value(&format!("JSON number {} cannot be represented", n))
+            })?)),
+        },
+        Value::String(s) => Box::new(StringTag::new(s.clone())),
+        Value::Array(items) => {
+            let mut list = ListTag::new(TagType::End);
+            for item in items {
+                list.push(infer(item, hints)?)?;
+            }
+            Box::new(list)
+        }
+        Value::Object(object) => Box::new(compound_from_json(object, hints)?),
+    };
+    Ok(tag)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn list_of(tag_type: TagType, items: Vec<Box<dyn Tag>>) -> Box<dyn Tag> {
+        let mut list = ListTag::new(tag_type);
+        for item in items {
+            list.push(item).unwrap();
+        }
+        Box::new(list)
+    }
+
+    fn round_trip(tag: Box<dyn Tag>) {
+        let mut compound = CompoundTag::new();
+        compound.set_tag("value".to_string(), tag).unwrap();
+        let json = to_json(&compound);
+        let decoded = from_json(&json, TypeHints::Embedded).unwrap();
+        assert!(decoded.equals(&compound), "{} decoded as {}", json, to_json(&*decoded));
+    }
+
+    #[test]
+    fn list_of_lists_with_different_element_types_round_trips() {
+        let ints = list_of(TagType::Int, vec![Box::new(IntTag::new(1)), Box::new(IntTag::new(2))]);
+        let strings = list_of(TagType::String, vec![Box::new(StringTag::new("a".to_string()))]);
+        round_trip(list_of(TagType::List, vec![ints, strings]));
+    }
+
+    #[test]
+    fn empty_lists_keep_their_element_type() {
+        let bytes = list_of(TagType::Byte, vec![Box::new(ByteTag::new(1))]);
+        let empty_doubles = list_of(TagType::Double, vec![]);
+        round_trip(list_of(TagType::List, vec![bytes, empty_doubles]));
+        round_trip(list_of(TagType::List, vec![]));
+    }
+
+    #[test]
+    fn deeply_nested_lists_round_trip() {
+        let longs = list_of(TagType::Long, vec![Box::new(LongTag::new(i64::MAX))]);
+        let floats = list_of(TagType::Float, vec![Box::new(FloatTag::new(0.5))]);
+        let inner = list_of(TagType::List, vec![longs, floats]);
+        let compounds = list_of(TagType::Compound, vec![Box::new(CompoundTag::new())]);
+        round_trip(list_of(TagType::List, vec![inner, compounds]));
+    }
+
+    #[test]
+    fn descriptor_count_mismatch_is_an_error() {
+        let json = serde_json::json!({ "value": [[1], [2]], TYPE_KEY: { "value": ["List:Int"] } });
+        assert!(from_json(&json, TypeHints::Embedded).is_err());
+    }
+}
@@ -0,0 +1,79 @@
+// src/nbt/level_data.rs
+#![allow(dead_code)]
+
+//! Typed view of the commonly used fields of a world's `level.dat`.
+//!
+//! Targets the Bedrock layout: the fields sit directly in the root compound, which is stored as
+//! little-endian NBT after an 8-byte header (storage version and payload length, both `i32` LE).
+//! The header is not handled here; pass the decoded root compound. Java Edition keeps the same
+//! keys inside a nested `"Data"` compound; pass that compound instead of the root.
+
+use crate::nbt::error::Result;
+use crate::nbt::tag::CompoundTag;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LevelData {
+    pub level_name: String,
+    pub spawn_x: i32,
+    pub spawn_y: i32,
+    pub spawn_z: i32,
+    /// 0 = survival, 1 = creative, 2 = adventure, 3 = spectator.
+    pub game_type: i32,
+    /// World age in ticks.
+    pub time: i64,
+    pub random_seed: i64,
+}
+
+impl LevelData {
+    pub const TAG_LEVEL_NAME: &'static str = "LevelName";
+    pub const TAG_SPAWN_X: &'static str = "SpawnX";
+    pub const TAG_SPAWN_Y: &'static str = "SpawnY";
+    pub const TAG_SPAWN_Z: &'static str = "SpawnZ";
+    pub const TAG_GAME_TYPE: &'static str = "GameType";
+    pub const TAG_TIME: &'static str = "Time";
+    pub const TAG_RANDOM_SEED: &'static str = "RandomSeed";
+
+    /// Reads the known fields, falling back to `LevelData::default()` for missing ones.
+    /// A key that exists with the wrong tag type is an error rather than silently defaulted.
+    pub fn from_compound(tag: &CompoundTag) -> Result<LevelData> {
+        let defaults = LevelData::default();
+        Ok(LevelData {
+            level_name: tag.get_string(Self::TAG_LEVEL_NAME, Some(defaults.level_name))?,
+            spawn_x: tag.get_int(Self::TAG_SPAWN_X, Some(defaults.spawn_x))?,
+            spawn_y: tag.get_int(Self::TAG_SPAWN_Y, Some(defaults.spawn_y))?,
+            spawn_z: tag.get_int(Self::TAG_SPAWN_Z, Some(defaults.spawn_z))?,
+            game_type: tag.get_int(Self::TAG_GAME_TYPE, Some(defaults.game_type))?,
+            time: tag.get_long(Self::TAG_TIME, Some(defaults.time))?,
+            random_seed: tag.get_long(Self::TAG_RANDOM_SEED, Some(defaults.random_seed))?,
+        })
+    }
+
+    /// Writes only the fields above. Use `original.merge(&data.to_compound())` to keep the
+    /// other keys of a loaded `level.dat`.
+    pub fn to_compound(&self) -> CompoundTag {
+        CompoundTag::builder()
+            .string(Self::TAG_LEVEL_NAME, self.level_name.clone())
+            .int(Self::TAG_SPAWN_X, self.spawn_x)
+            .int(Self::TAG_SPAWN_Y, self.spawn_y)
+            .int(Self::TAG_SPAWN_Z, self.spawn_z)
+            .int(Self::TAG_GAME_TYPE, self.game_type)
+            .long(Self::TAG_TIME, self.time)
+            .long(Self::TAG_RANDOM_SEED, self.random_seed)
+            .build()
+            .expect("level.dat key names are within the NBT name length limit")
+    }
+}
+
+impl Default for LevelData {
+    fn default() -> Self {
+        Self {
+            level_name: "world".to_string(),
+            spawn_x: 0,
+            spawn_y: 64,
+            spawn_z: 0,
+            game_type: 0,
+            time: 0,
+            random_seed: 0,
+        }
+    }
+}
@@ -3,11 +3,14 @@
 
 use crate::utils::{BinaryStream, limits};
 use crate::nbt::error::{NbtError, Result};
+use crate::nbt::io_serializer::{IoNbtReader, IoNbtWriter};
 use crate::nbt::serializer::{NbtRead, NbtWrite, NbtReader, NbtWriter};
 use crate::nbt::tag::{self, Tag, TagType};
-use crate::nbt::reader_tracker::ReaderTracker;
+use crate::nbt::reader_tracker::{ReaderLimits, ReaderTracker};
 use crate::nbt::tree_root::TreeRoot;
+use byteorder::LittleEndian;
 use std::convert::TryInto;
+use std::io::{Read, Write};
 
 
 pub struct LittleEndianNbtSerializer {
@@ -24,16 +27,16 @@ impl LittleEndianNbtSerializer {
     }
 
     // --- Root Read/Write Logic ---
-    fn read_root(&mut self, max_depth: usize) -> Result<TreeRoot> {
+    fn read_root(&mut self, max_depth: usize, limits: ReaderLimits) -> Result<TreeRoot> {
         let type_id = self.read_byte()?;
         if type_id == TagType::End as u8 {
             return Err(NbtError::new_data_error("Found TAG_End at the start of buffer"));
         }
         let tag_type = TagType::from_id(type_id)
-            .ok_or_else(|| NbtError::new_data_error(&format!("Invalid root tag type ID: {}", type_id)))?;
+            .ok_or(NbtError::InvalidTagType(type_id))?;
 
-        let root_name = self.read_string()?;
-        let mut tracker = ReaderTracker::new(max_depth);
+        let mut tracker = ReaderTracker::with_limits(max_depth, limits);
+        let root_name = self.read_string(&mut tracker)?;
         let root_tag = tag::create_tag(tag_type, self, &mut tracker)?;
         TreeRoot::new(root_name, root_tag)
     }
@@ -46,13 +49,18 @@ impl LittleEndianNbtSerializer {
 
     // --- Public API ---
     pub fn read(&mut self, max_depth: usize) -> Result<TreeRoot> {
+        self.read_with_limits(max_depth, ReaderLimits::default())
+    }
+
+    /// Like `read`, with explicit size limits instead of `ReaderLimits::default()`.
+    pub fn read_with_limits(&mut self, max_depth: usize, limits: ReaderLimits) -> Result<TreeRoot> {
         self.stream.rewind();
-        self.read_root(max_depth)
+        self.read_root(max_depth, limits)
     }
 
     pub fn read_from_buffer(buffer: &[u8], max_depth: usize) -> Result<TreeRoot> {
         let mut serializer = Self::from_bytes(buffer);
-        serializer.read_root(max_depth)
+        serializer.read_root(max_depth, ReaderLimits::default())
     }
 
     pub fn write(&mut self, data: &TreeRoot) -> Result<()> {
@@ -67,13 +75,31 @@ impl LittleEndianNbtSerializer {
         Ok(serializer.stream.get_buffer().to_vec())
     }
 
+    /// Streams `root` to `writer` without building the encoding in memory first.
+    pub fn write_to(writer: &mut impl Write, root: &TreeRoot) -> Result<()> {
+        IoNbtWriter::<_, LittleEndian>::new(writer).write_root(root)
+    }
+
+    /// Reads one root tag from `reader`, consuming only its bytes.
+    pub fn read_from(reader: &mut impl Read, max_depth: usize) -> Result<TreeRoot> {
+        Self::read_from_with_limits(reader, max_depth, ReaderLimits::default())
+    }
+
+    pub fn read_from_with_limits(reader: &mut impl Read, max_depth: usize, limits: ReaderLimits) -> Result<TreeRoot> {
+        IoNbtReader::<_, LittleEndian>::new(reader).read_root(max_depth, limits)
+    }
+
     pub fn read_headless(&mut self, root_type_id: u8, max_depth: usize) -> Result<Box<dyn Tag>> {
+        self.read_headless_with_limits(root_type_id, max_depth, ReaderLimits::default())
+    }
+
+    pub fn read_headless_with_limits(&mut self, root_type_id: u8, max_depth: usize, limits: ReaderLimits) -> Result<Box<dyn Tag>> {
         let root_type = TagType::from_id(root_type_id)
-            .ok_or_else(|| NbtError::new_data_error(&format!("Invalid headless root tag type ID: {}", root_type_id)))?;
+            .ok_or(NbtError::InvalidTagType(root_type_id))?;
         if root_type == TagType::End {
             return Err(NbtError::new_data_error("Cannot read headless TAG_End"));
         }
-        let mut tracker = ReaderTracker::new(max_depth);
+        let mut tracker = ReaderTracker::with_limits(max_depth, limits);
         tag::create_tag(root_type, self, &mut tracker)
     }
 
@@ -97,16 +123,10 @@ impl LittleEndianNbtSerializer {
         let mut results = Vec::new();
         while !self.stream.feof() {
             let current_offset = self.stream.get_offset();
-            match self.read_root(max_depth) {
+            match self.read_root(max_depth, ReaderLimits::default()) {
                 Ok(root) => results.push(root),
-                Err(NbtError::IoError(e)) => { // Match on the error variant directly
-                    if self.stream.get_offset() == current_offset && e.to_string().contains("Not enough bytes") {
-                        break; // Clean EOF suspected
-                    }
-                    else {
-                        return Err(NbtError::IoError(e)); // Propagate other IO errors or partial reads
-                    }
-                }
+                // Running out of input before a new root started is a clean end; a partial root is not
+                Err(NbtError::UnexpectedEof(_)) if self.stream.get_offset() == current_offset => break,
                 Err(e) => return Err(e),
             }
         }
@@ -165,28 +185,35 @@ impl NbtRead for LittleEndianNbtSerializer {
     fn read_float(&mut self) -> Result<f32> { Ok(self.stream.get_lfloat()?) }
     fn read_double(&mut self) -> Result<f64> { Ok(self.stream.get_ldouble()?) }
 
-    fn read_byte_array(&mut self) -> Result<Vec<u8>> {
+    fn read_byte_array(&mut self, tracker: &mut ReaderTracker) -> Result<Vec<u8>> {
         let length = self.read_int()?;
         if length < 0 {
             return Err(NbtError::new_data_error(&format!("ByteArray length cannot be less than zero ({})", length)));
         }
         let usize_length: usize = length.try_into().map_err(|_| NbtError::new_data_error("ByteArray length too large"))?;
+        tracker.consume_bytes(usize_length)?;
         Ok(self.stream.get(usize_length)?.to_vec())
     }
 
-    fn read_string(&mut self) -> Result<String> {
+    fn read_string(&mut self, tracker: &mut ReaderTracker) -> Result<String> {
         let length = self.read_short()?;
         let usize_length = Self::check_read_string_length(length)?;
+        tracker.consume_bytes(usize_length)?;
         let bytes = self.stream.get(usize_length)?;
         String::from_utf8(bytes.to_vec()).map_err(NbtError::from)
     }
 
-    fn read_int_array(&mut self) -> Result<Vec<i32>> {
+    fn read_int_array(&mut self, tracker: &mut ReaderTracker) -> Result<Vec<i32>> {
         let length = self.read_int()?;
         if length < 0 {
             return Err(NbtError::new_data_error(&format!("IntArray length cannot be less than zero ({})", length)));
         }
         let usize_length: usize = length.try_into().map_err(|_| NbtError::new_data_error("IntArray length too large"))?;
+        // Reject lengths the buffer cannot back before allocating for them
+        if usize_length > self.stream.remaining() / 4 {
+            return Err(NbtError::new_unexpected_eof(&format!("IntArray length {} exceeds remaining data", usize_length)));
+        }
+        tracker.consume_bytes(usize_length.saturating_mul(std::mem::size_of::<i32>()))?;
         let mut result = Vec::with_capacity(usize_length);
         for _ in 0..usize_length {
             result.push(self.read_int()?);
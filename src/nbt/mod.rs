@@ -2,7 +2,13 @@
 #![allow(dead_code)]
 
 pub mod error;
+pub mod io_serializer;
+#[cfg(feature = "serde")]
+pub mod json;
+pub mod level_data;
 pub mod reader_tracker;
+#[cfg(feature = "region")]
+pub mod region;
 pub mod serializer;
 pub mod tag;
 pub mod tree_root;
@@ -11,6 +17,12 @@ pub mod little_endian_serializer;
 
 // Re-export necessary types
 pub use error::{NbtError, Result};
+#[cfg(feature = "serde")]
+pub use json::{from_json, to_json, TypeHints};
+pub use level_data::LevelData;
+pub use reader_tracker::ReaderLimits;
+#[cfg(feature = "region")]
+pub use region::RegionReader;
 pub use tag::{CompoundTag, ListTag, Tag, TagType}; // NbtTag removed from re-export
 pub use tree_root::TreeRoot;
 pub use big_endian_serializer::BigEndianNbtSerializer;
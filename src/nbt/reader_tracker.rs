@@ -3,16 +3,62 @@
 
 use crate::nbt::error::{NbtError, Result};
 
+/// Size limits enforced while decoding, so that length fields in untrusted NBT (e.g. from the
+/// network) cannot make the reader allocate unbounded memory. A limit of 0 disables that check.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ReaderLimits {
+    pub max_list_length: usize,
+    pub max_compound_entries: usize,
+    /// Approximate upper bound on memory used by the decoded tree, counting tag structs,
+    /// list/compound slots, names and array/string payloads.
+    pub max_total_bytes: usize,
+}
+
+impl ReaderLimits {
+    pub const DEFAULT_MAX_LIST_LENGTH: usize = 1 << 20;
+    pub const DEFAULT_MAX_COMPOUND_ENTRIES: usize = 1 << 20;
+    pub const DEFAULT_MAX_TOTAL_BYTES: usize = 64 * 1024 * 1024;
+
+    pub const fn unlimited() -> Self {
+        Self { max_list_length: 0, max_compound_entries: 0, max_total_bytes: 0 }
+    }
+}
+
+impl Default for ReaderLimits {
+    fn default() -> Self {
+        Self {
+            max_list_length: Self::DEFAULT_MAX_LIST_LENGTH,
+            max_compound_entries: Self::DEFAULT_MAX_COMPOUND_ENTRIES,
+            max_total_bytes: Self::DEFAULT_MAX_TOTAL_BYTES,
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct ReaderTracker {
     max_depth: usize,
     current_depth: usize,
+    limits: ReaderLimits,
+    total_bytes: usize,
 }
 
 impl ReaderTracker {
     pub fn new(max_depth: usize) -> Self {
+        Self::with_limits(max_depth, ReaderLimits::default())
+    }
+
+    pub fn with_limits(max_depth: usize, limits: ReaderLimits) -> Self {
         // Depth 0 means no limit
-        Self { max_depth, current_depth: 0 }
+        Self { max_depth, current_depth: 0, limits, total_bytes: 0 }
+    }
+
+    pub fn get_limits(&self) -> &ReaderLimits {
+        &self.limits
+    }
+
+    /// Bytes accounted so far by `consume_bytes`.
+    pub fn get_total_bytes(&self) -> usize {
+        self.total_bytes
     }
 
     // Internal function called by create_tag for compound/list
@@ -39,4 +85,87 @@ impl ReaderTracker {
                 .expect("Depth underflow, decrease called without increase");
         }
     }
-}
\ No newline at end of file
+
+    // Called by ListTag::read with the declared size, before anything is allocated
+    pub(crate) fn check_list_length(&self, length: usize) -> Result<()> {
+        if self.limits.max_list_length > 0 && length > self.limits.max_list_length {
+            return Err(NbtError::new_size_limit_exceeded(&format!(
+                "ListTag length {} exceeds limit {}", length, self.limits.max_list_length
+            )));
+        }
+        Ok(())
+    }
+
+    // Called by CompoundTag::read with the entry count after adding one more entry
+    pub(crate) fn check_compound_entries(&self, count: usize) -> Result<()> {
+        if self.limits.max_compound_entries > 0 && count > self.limits.max_compound_entries {
+            return Err(NbtError::new_size_limit_exceeded(&format!(
+                "CompoundTag entry count exceeds limit {}", self.limits.max_compound_entries
+            )));
+        }
+        Ok(())
+    }
+
+    pub(crate) fn consume_bytes(&mut self, bytes: usize) -> Result<()> {
+        self.total_bytes = self.total_bytes.saturating_add(bytes);
+        if self.limits.max_total_bytes > 0 && self.total_bytes > self.limits.max_total_bytes {
+            return Err(NbtError::new_size_limit_exceeded(&format!(
+                "Decoded NBT exceeds the limit of {} bytes", self.limits.max_total_bytes
+            )));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nbt::big_endian_serializer::BigEndianNbtSerializer;
+    use crate::nbt::tag::ByteArrayTag;
+
+    fn limits(max_total_bytes: usize) -> ReaderLimits {
+        ReaderLimits { max_total_bytes, ..ReaderLimits::default() }
+    }
+
+    // Root ByteArray tag with an empty name, followed by `payload` bytes
+    fn byte_array_root(declared: i32, payload: usize) -> Vec<u8> {
+        let mut data = vec![7, 0, 0];
+        data.extend_from_slice(&declared.to_be_bytes());
+        data.resize(data.len() + payload, 0xAB);
+        data
+    }
+
+    #[test]
+    fn byte_array_payload_is_charged_before_reading() {
+        let data = byte_array_root(2048, 2048);
+        let err = BigEndianNbtSerializer::from_bytes(&data).read_with_limits(0, limits(1024)).unwrap_err();
+        assert!(matches!(err, NbtError::SizeLimitExceeded(_)), "{:?}", err);
+    }
+
+    #[test]
+    fn string_payload_is_charged_before_reading() {
+        let mut data = vec![8, 0, 0, 0x7F, 0xFF];
+        data.resize(data.len() + 0x7FFF, b'a');
+        let err = BigEndianNbtSerializer::from_bytes(&data).read_with_limits(0, limits(1024)).unwrap_err();
+        assert!(matches!(err, NbtError::SizeLimitExceeded(_)), "{:?}", err);
+    }
+
+    #[test]
+    fn payload_within_budget_is_counted_once() {
+        // Exactly the payload plus the tag struct fits; charging the payload twice would not
+        let budget = 100 + std::mem::size_of::<ByteArrayTag>();
+        let data = byte_array_root(100, 100);
+        let root = BigEndianNbtSerializer::from_bytes(&data).read_with_limits(0, limits(budget)).unwrap();
+        assert_eq!(root.get_tag().get_value().downcast_ref::<Vec<u8>>().unwrap().len(), 100);
+
+        let data = byte_array_root(101, 101);
+        assert!(BigEndianNbtSerializer::from_bytes(&data).read_with_limits(0, limits(budget)).is_err());
+    }
+
+    #[test]
+    fn zero_disables_the_byte_limit() {
+        let mut tracker = ReaderTracker::with_limits(0, ReaderLimits::unlimited());
+        tracker.consume_bytes(usize::MAX).unwrap();
+        tracker.consume_bytes(1).unwrap();
+    }
+}
@@ -0,0 +1,251 @@
+// src/nbt/region.rs
+#![allow(dead_code)]
+
+//! Reader for Anvil region files (`r.<x>.<z>.mca`), which hold 32x32 chunks of big-endian NBT.
+//!
+//! The file starts with two 4KiB tables of 1024 big-endian `u32` entries, indexed by
+//! `(cx & 31) + (cz & 31) * 32`:
+//! - locations: the high 24 bits are the chunk's offset and the low 8 bits its length, both in
+//!   4KiB sectors. An all-zero entry means the chunk has not been generated.
+//! - timestamps: last modification time in seconds since the Unix epoch.
+//!
+//! A chunk's sectors start with a big-endian `u32` byte length (counting the next byte), a
+//! compression type byte (1 = gzip, 2 = zlib, 3 = uncompressed) and the compressed NBT. LZ4 (4),
+//! custom (127) and chunks stored in external `.mcc` files (bit 0x80 set) are not supported.
+
+use crate::nbt::big_endian_serializer::BigEndianNbtSerializer;
+use crate::nbt::error::{NbtError, Result};
+use crate::nbt::reader_tracker::ReaderLimits;
+use crate::nbt::tree_root::TreeRoot;
+use flate2::read::{GzDecoder, ZlibDecoder};
+use std::fs::File;
+use std::io::{BufReader, Read, Seek, SeekFrom};
+use std::path::Path;
+
+pub const SECTOR_SIZE: usize = 4096;
+pub const CHUNKS_PER_REGION: usize = 1024;
+
+const COMPRESSION_GZIP: u8 = 1;
+const COMPRESSION_ZLIB: u8 = 2;
+const COMPRESSION_NONE: u8 = 3;
+const EXTERNAL_CHUNK_FLAG: u8 = 0x80;
+
+#[derive(Debug)]
+pub struct RegionReader<R: Read + Seek> {
+    reader: R,
+    locations: [u32; CHUNKS_PER_REGION],
+    timestamps: [u32; CHUNKS_PER_REGION],
+    max_depth: usize,
+    limits: ReaderLimits,
+}
+
+impl RegionReader<BufReader<File>> {
+    pub fn open(path: impl AsRef<Path>, max_depth: usize) -> Result<Self> {
+        Self::new(BufReader::new(File::open(path)?), max_depth)
+    }
+}
+
+impl<R: Read + Seek> RegionReader<R> {
+    /// Reads the header tables. `max_depth` is passed to the NBT reader for every chunk.
+    pub fn new(mut reader: R, max_depth: usize) -> Result<Self> {
+        let mut header = vec![0u8; SECTOR_SIZE * 2];
+        reader.seek(SeekFrom::Start(0))?;
+        reader.read_exact(&mut header)?;
+
+        let mut locations = [0u32; CHUNKS_PER_REGION];
+        let mut timestamps = [0u32; CHUNKS_PER_REGION];
+        for i in 0..CHUNKS_PER_REGION {
+            locations[i] = u32::from_be_bytes(header[i * 4..i * 4 + 4].try_into().unwrap());
+            let t = SECTOR_SIZE + i * 4;
+            timestamps[i] = u32::from_be_bytes(header[t..t + 4].try_into().unwrap());
+        }
+        Ok(Self { reader, locations, timestamps, max_depth, limits: ReaderLimits::default() })
+    }
+
+    /// Limits applied when decoding chunks. `max_total_bytes` also caps the decompressed size.
+    pub fn set_limits(&mut self, limits: ReaderLimits) {
+        self.limits = limits;
+    }
+
+    /// Index into the header tables. Absolute chunk coordinates are accepted; only the position
+    /// within the region (the low 5 bits) is used.
+    pub fn get_chunk_index(cx: i32, cz: i32) -> usize {
+        ((cx & 31) + (cz & 31) * 32) as usize
+    }
+
+    pub fn has_chunk(&self, cx: i32, cz: i32) -> bool {
+        self.locations[Self::get_chunk_index(cx, cz)] != 0
+    }
+
+    /// Last modification time of the chunk in seconds since the Unix epoch, 0 if unknown.
+    pub fn get_timestamp(&self, cx: i32, cz: i32) -> u32 {
+        self.timestamps[Self::get_chunk_index(cx, cz)]
+    }
+
+    /// Reads and decompresses a chunk. Returns `Ok(None)` if the chunk is not present.
+    pub fn get_chunk(&mut self, cx: i32, cz: i32) -> Result<Option<TreeRoot>> {
+        let location = self.locations[Self::get_chunk_index(cx, cz)];
+        if location == 0 {
+            return Ok(None);
+        }
+        let sector_offset = (location >> 8) as u64;
+        let sector_count = (location & 0xff) as usize;
+        if sector_offset < 2 || sector_count == 0 {
+            return Err(NbtError::new_data_error(&format!(
+                "Chunk {},{} has an invalid location (sector {}, {} sectors)", cx, cz, sector_offset, sector_count
+            )));
+        }
+
+        self.reader.seek(SeekFrom::Start(sector_offset * SECTOR_SIZE as u64))?;
+        let mut prefix = [0u8; 5];
+        self.reader.read_exact(&mut prefix)?;
+        let length = u32::from_be_bytes([prefix[0], prefix[1], prefix[2], prefix[3]]) as usize;
+        let compression = prefix[4];
+        if length == 0 || length + 4 > sector_count * SECTOR_SIZE {
+            return Err(NbtError::new_data_error(&format!(
+                "Chunk {},{} length {} does not fit in its {} sectors", cx, cz, length, sector_count
+            )));
+        }
+        if compression & EXTERNAL_CHUNK_FLAG != 0 {
+            return Err(NbtError::new_invalid_operation(&format!(
+                "Chunk {},{} is stored in an external .mcc file, which is not supported", cx, cz
+            )));
+        }
+
+        let max_bytes = self.limits.max_total_bytes;
+        let compressed = (&mut self.reader).take((length - 1) as u64);
+        let data = match compression {
+            COMPRESSION_GZIP => Self::read_capped(GzDecoder::new(compressed), max_bytes)?,
+            COMPRESSION_ZLIB => Self::read_capped(ZlibDecoder::new(compressed), max_bytes)?,
+            COMPRESSION_NONE => Self::read_capped(compressed, max_bytes)?,
+            other => return Err(NbtError::new_data_error(&format!(
+                "Chunk {},{} uses unsupported compression type {}", cx, cz, other
+            ))),
+        };
+
+        BigEndianNbtSerializer::from_bytes(&data)
+            .read_with_limits(self.max_depth, self.limits)
+            .map(Some)
+    }
+
+    // Reads at most `max_bytes` (0 = unlimited) so a small compressed chunk cannot inflate
+    // into an arbitrarily large buffer
+    fn read_capped(source: impl Read, max_bytes: usize) -> Result<Vec<u8>> {
+        let cap = if max_bytes == 0 { u64::MAX } else { max_bytes as u64 + 1 };
+        let mut data = Vec::new();
+        source.take(cap).read_to_end(&mut data)?;
+        if max_bytes > 0 && data.len() > max_bytes {
+            return Err(NbtError::new_size_limit_exceeded(&format!(
+                "Decompressed chunk exceeds the limit of {} bytes", max_bytes
+            )));
+        }
+        Ok(data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nbt::tag::{ByteArrayTag, CompoundTag};
+    use flate2::write::ZlibEncoder;
+    use flate2::Compression;
+    use std::io::{Cursor, Write};
+
+    fn chunk_nbt(x: i32) -> Vec<u8> {
+        let mut tag = CompoundTag::new();
+        tag.set_int("xPos".to_string(), x).unwrap();
+        BigEndianNbtSerializer::write_to_bytes(&TreeRoot::new(String::new(), Box::new(tag)).unwrap()).unwrap()
+    }
+
+    fn zlib(data: &[u8]) -> Vec<u8> {
+        let mut encoder = ZlibEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(data).unwrap();
+        encoder.finish().unwrap()
+    }
+
+    // Region file with each chunk in its own run of sectors after the header, in the given order.
+    // Timestamps are the chunk index plus 1000.
+    fn region(chunks: &[(usize, u8, Vec<u8>)]) -> Cursor<Vec<u8>> {
+        let mut file = vec![0u8; SECTOR_SIZE * 2];
+        for (index, compression, payload) in chunks {
+            let sector = file.len() / SECTOR_SIZE;
+            file.extend_from_slice(&(payload.len() as u32 + 1).to_be_bytes());
+            file.push(*compression);
+            file.extend_from_slice(payload);
+            file.resize(file.len().div_ceil(SECTOR_SIZE) * SECTOR_SIZE, 0);
+            let sectors = file.len() / SECTOR_SIZE - sector;
+            let location = ((sector as u32) << 8) | sectors as u32;
+            file[index * 4..index * 4 + 4].copy_from_slice(&location.to_be_bytes());
+            let t = SECTOR_SIZE + index * 4;
+            file[t..t + 4].copy_from_slice(&(*index as u32 + 1000).to_be_bytes());
+        }
+        Cursor::new(file)
+    }
+
+    fn x_pos(root: &TreeRoot) -> i32 {
+        root.must_get_compound_tag().unwrap().get_int("xPos", None).unwrap()
+    }
+
+    #[test]
+    fn reads_compressed_and_uncompressed_chunks() {
+        let mut reader = RegionReader::new(region(&[
+            (0, COMPRESSION_ZLIB, zlib(&chunk_nbt(0))),
+            (2, COMPRESSION_NONE, chunk_nbt(2)),
+        ]), 0).unwrap();
+
+        assert!(reader.has_chunk(0, 0));
+        assert_eq!(reader.get_timestamp(0, 0), 1000);
+        assert_eq!(x_pos(&reader.get_chunk(0, 0).unwrap().unwrap()), 0);
+        // Absolute coordinates map onto the same slot
+        assert_eq!(x_pos(&reader.get_chunk(34, -32).unwrap().unwrap()), 2);
+        assert_eq!(RegionReader::<Cursor<Vec<u8>>>::get_chunk_index(34, -32), 2);
+    }
+
+    #[test]
+    fn empty_slot_is_none() {
+        let mut reader = RegionReader::new(region(&[(0, COMPRESSION_NONE, chunk_nbt(0))]), 0).unwrap();
+        assert!(!reader.has_chunk(1, 0));
+        assert_eq!(reader.get_timestamp(1, 0), 0);
+        assert!(reader.get_chunk(1, 0).unwrap().is_none());
+    }
+
+    #[test]
+    fn decompressed_size_is_capped_by_byte_limit() {
+        // 64KiB of zeros compresses to a few hundred bytes
+        let root = TreeRoot::new(String::new(), Box::new(ByteArrayTag::new(vec![0; 64 * 1024]))).unwrap();
+        let payload = zlib(&BigEndianNbtSerializer::write_to_bytes(&root).unwrap());
+        assert!(payload.len() < SECTOR_SIZE);
+
+        let mut reader = RegionReader::new(region(&[(0, COMPRESSION_ZLIB, payload)]), 0).unwrap();
+        reader.set_limits(ReaderLimits { max_total_bytes: 16 * 1024, ..ReaderLimits::default() });
+        let err = reader.get_chunk(0, 0).unwrap_err();
+        assert!(matches!(err, NbtError::SizeLimitExceeded(_)), "{:?}", err);
+
+        reader.set_limits(ReaderLimits::default());
+        assert!(reader.get_chunk(0, 0).unwrap().is_some());
+    }
+
+    #[test]
+    fn malformed_chunks_are_rejected() {
+        let mut file = region(&[
+            (0, 9, chunk_nbt(0)),
+            (1, COMPRESSION_ZLIB | EXTERNAL_CHUNK_FLAG, Vec::new()),
+            (2, COMPRESSION_NONE, chunk_nbt(2)),
+        ]).into_inner();
+        // Point chunk 3 into the header
+        file[12..16].copy_from_slice(&((1u32 << 8) | 1).to_be_bytes());
+        // Give chunk 2 a length longer than its single sector
+        file[4 * SECTOR_SIZE..4 * SECTOR_SIZE + 4].copy_from_slice(&(SECTOR_SIZE as u32).to_be_bytes());
+
+        let mut reader = RegionReader::new(Cursor::new(file), 0).unwrap();
+        assert!(matches!(reader.get_chunk(0, 0).unwrap_err(), NbtError::DataError(_)));
+        assert!(matches!(reader.get_chunk(1, 0).unwrap_err(), NbtError::InvalidOperation(_)));
+        assert!(matches!(reader.get_chunk(2, 0).unwrap_err(), NbtError::DataError(_)));
+        assert!(matches!(reader.get_chunk(3, 0).unwrap_err(), NbtError::DataError(_)));
+    }
+
+    #[test]
+    fn truncated_header_is_rejected() {
+        assert!(RegionReader::new(Cursor::new(vec![0u8; SECTOR_SIZE]), 0).is_err());
+    }
+}
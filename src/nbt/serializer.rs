@@ -3,6 +3,7 @@
 
 use crate::utils::BinaryStream;
 use crate::nbt::error::Result;
+use crate::nbt::reader_tracker::ReaderTracker;
 
 // No NbtTag import needed here
 
@@ -16,9 +17,11 @@ pub trait NbtRead {
     fn read_long(&mut self) -> Result<i64>;
     fn read_float(&mut self) -> Result<f32>;
     fn read_double(&mut self) -> Result<f64>;
-    fn read_byte_array(&mut self) -> Result<Vec<u8>>;
-    fn read_string(&mut self) -> Result<String>;
-    fn read_int_array(&mut self) -> Result<Vec<i32>>;
+    // Variable-length reads charge their declared payload size to `tracker` before allocating,
+    // so `max_total_bytes` bounds what a forged length prefix can make the reader reserve
+    fn read_byte_array(&mut self, tracker: &mut ReaderTracker) -> Result<Vec<u8>>;
+    fn read_string(&mut self, tracker: &mut ReaderTracker) -> Result<String>;
+    fn read_int_array(&mut self, tracker: &mut ReaderTracker) -> Result<Vec<i32>>;
 }
 
 // Equivalent to NbtStreamWriter in PHP
@@ -0,0 +1,141 @@
+// src/nbt/tag/builder.rs
+#![allow(dead_code)]
+
+use crate::nbt::error::{NbtError, Result};
+use crate::nbt::tag::tag::{Tag, TagType};
+use super::{
+    ByteTag, ShortTag, IntTag, LongTag, FloatTag, DoubleTag,
+    ByteArrayTag, StringTag, ListTag, CompoundTag, IntArrayTag
+};
+
+/// Fluent builder for `CompoundTag`, created with `CompoundTag::builder()`.
+///
+/// The first failing insert (e.g. an over-long name) is remembered and returned from `build`;
+/// later calls are ignored.
+#[derive(Debug)]
+pub struct CompoundTagBuilder {
+    tag: CompoundTag,
+    error: Option<NbtError>,
+}
+
+impl CompoundTagBuilder {
+    pub fn new() -> Self {
+        Self { tag: CompoundTag::new(), error: None }
+    }
+
+    pub fn tag(mut self, name: impl Into<String>, tag: Box<dyn Tag>) -> Self {
+        if self.error.is_none() && let Err(e) = self.tag.set_tag(name.into(), tag) {
+            self.error = Some(e);
+        }
+        self
+    }
+
+    pub fn byte(self, name: impl Into<String>, value: i8) -> Self { self.tag(name, Box::new(ByteTag::new(value))) }
+    pub fn short(self, name: impl Into<String>, value: i16) -> Self { self.tag(name, Box::new(ShortTag::new(value))) }
+    pub fn int(self, name: impl Into<String>, value: i32) -> Self { self.tag(name, Box::new(IntTag::new(value))) }
+    pub fn long(self, name: impl Into<String>, value: i64) -> Self { self.tag(name, Box::new(LongTag::new(value))) }
+    pub fn float(self, name: impl Into<String>, value: f32) -> Self { self.tag(name, Box::new(FloatTag::new(value))) }
+    pub fn double(self, name: impl Into<String>, value: f64) -> Self { self.tag(name, Box::new(DoubleTag::new(value))) }
+    pub fn byte_array(self, name: impl Into<String>, value: Vec<u8>) -> Self { self.tag(name, Box::new(ByteArrayTag::new(value))) }
+    pub fn string(self, name: impl Into<String>, value: impl Into<String>) -> Self { self.tag(name, Box::new(StringTag::new(value.into()))) }
+    pub fn int_array(self, name: impl Into<String>, value: Vec<i32>) -> Self { self.tag(name, Box::new(IntArrayTag::new(value))) }
+    pub fn list(self, name: impl Into<String>, value: ListTag) -> Self { self.tag(name, Box::new(value)) }
+    pub fn compound(self, name: impl Into<String>, value: CompoundTag) -> Self { self.tag(name, Box::new(value)) }
+
+    pub fn build(self) -> Result<CompoundTag> {
+        match self.error {
+            Some(e) => Err(e),
+            None => Ok(self.tag),
+        }
+    }
+}
+
+impl Default for CompoundTagBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Fluent builder for `ListTag`, created with `ListTag::builder(tag_type)`.
+///
+/// Every element is checked against the list's tag type; the first mismatch is returned from
+/// `build`.
+#[derive(Debug)]
+pub struct ListTagBuilder {
+    tag: ListTag,
+    error: Option<NbtError>,
+}
+
+impl ListTagBuilder {
+    pub fn new(tag_type: TagType) -> Self {
+        Self { tag: ListTag::new(tag_type), error: None }
+    }
+
+    pub fn tag(mut self, tag: Box<dyn Tag>) -> Self {
+        if self.error.is_none() && let Err(e) = self.tag.push(tag) {
+            self.error = Some(e);
+        }
+        self
+    }
+
+    pub fn byte(self, value: i8) -> Self { self.tag(Box::new(ByteTag::new(value))) }
+    pub fn short(self, value: i16) -> Self { self.tag(Box::new(ShortTag::new(value))) }
+    pub fn int(self, value: i32) -> Self { self.tag(Box::new(IntTag::new(value))) }
+    pub fn long(self, value: i64) -> Self { self.tag(Box::new(LongTag::new(value))) }
+    pub fn float(self, value: f32) -> Self { self.tag(Box::new(FloatTag::new(value))) }
+    pub fn double(self, value: f64) -> Self { self.tag(Box::new(DoubleTag::new(value))) }
+    pub fn byte_array(self, value: Vec<u8>) -> Self { self.tag(Box::new(ByteArrayTag::new(value))) }
+    pub fn string(self, value: impl Into<String>) -> Self { self.tag(Box::new(StringTag::new(value.into()))) }
+    pub fn int_array(self, value: Vec<i32>) -> Self { self.tag(Box::new(IntArrayTag::new(value))) }
+    pub fn list(self, value: ListTag) -> Self { self.tag(Box::new(value)) }
+    pub fn compound(self, value: CompoundTag) -> Self { self.tag(Box::new(value)) }
+
+    pub fn build(self) -> Result<ListTag> {
+        match self.error {
+            Some(e) => Err(e),
+            None => Ok(self.tag),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn nested_build_matches_hand_built_tag() {
+        let built = CompoundTag::builder()
+            .string("id", "minecraft:zombie")
+            .int("health", 20)
+            .list("pos", ListTag::builder(TagType::Double).double(1.5).double(64.0).double(-3.0).build().unwrap())
+            .compound("attributes", CompoundTag::builder().byte("baby", 1).build().unwrap())
+            .build()
+            .unwrap();
+
+        let mut pos = ListTag::new(TagType::Double);
+        for v in [1.5, 64.0, -3.0] {
+            pos.push(Box::new(DoubleTag::new(v))).unwrap();
+        }
+        let mut attributes = CompoundTag::new();
+        attributes.set_byte("baby".to_string(), 1).unwrap();
+        let mut expected = CompoundTag::new();
+        expected.set_string("id".to_string(), "minecraft:zombie".to_string()).unwrap();
+        expected.set_int("health".to_string(), 20).unwrap();
+        expected.set_list("pos".to_string(), pos).unwrap();
+        expected.set_compound("attributes".to_string(), attributes).unwrap();
+
+        assert_eq!(built, expected);
+    }
+
+    #[test]
+    fn list_builder_rejects_mismatched_element() {
+        let err = ListTag::builder(TagType::Int).int(1).string("two").int(3).build().unwrap_err();
+        assert!(matches!(err, NbtError::UnexpectedTagType(_)), "{:?}", err);
+    }
+
+    #[test]
+    fn compound_builder_reports_first_error() {
+        let long_name = "a".repeat(i16::MAX as usize + 1);
+        assert!(CompoundTag::builder().int(long_name, 1).int("ok", 2).build().is_err());
+    }
+}
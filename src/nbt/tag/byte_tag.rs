@@ -2,7 +2,7 @@
 #![allow(dead_code)]
 
 use crate::nbt::error::Result;
-use crate::nbt::serializer::{NbtReader, NbtWriter}; // Removed NbtWrite
+use crate::nbt::serializer::{NbtRead, NbtWrite};
 use crate::nbt::tag::tag::{IntegerishTag, Tag, TagType};
 use std::any::Any;
 use std::fmt;
@@ -17,7 +17,7 @@ impl ByteTag {
         Self { value }
     }
 
-    pub fn read(reader: &mut dyn NbtReader) -> Result<Self> {
+    pub fn read(reader: &mut dyn NbtRead) -> Result<Self> {
         Ok(Self::new(reader.read_signed_byte()?))
     }
 }
@@ -32,7 +32,7 @@ impl Tag for ByteTag {
         TagType::Byte
     }
 
-    fn write(&self, writer: &mut dyn NbtWriter) -> Result<()> {
+    fn write(&self, writer: &mut dyn NbtWrite) -> Result<()> {
         writer.write_signed_byte(self.value)
     }
 
@@ -2,7 +2,7 @@
 #![allow(dead_code)]
 
 use crate::nbt::error::{NbtError, Result};
-use crate::nbt::serializer::{NbtReader, NbtWriter};
+use crate::nbt::serializer::{NbtRead, NbtWrite};
 use crate::nbt::tag::tag::{Tag, TagType};
 use crate::nbt::tag; // For create_tag factory
 use crate::nbt::reader_tracker::ReaderTracker;
@@ -15,9 +15,11 @@ use std::fmt;
 // Import specific tag types for getters/setters and From impls
 use super::{
     ByteTag, ShortTag, IntTag, LongTag, FloatTag, DoubleTag,
-    ByteArrayTag, StringTag, ListTag, IntArrayTag
+    ByteArrayTag, StringTag, ListTag, IntArrayTag, CompoundTagBuilder
 };
 
+/// Named tags backed by a `HashMap`. Iteration order (`keys`, `iter`, and the order tags are
+/// written in) is unspecified and may differ between runs; it does not follow insertion order.
 #[derive(Debug, Clone)]
 pub struct CompoundTag {
     value: HashMap<String, Box<dyn Tag>>,
@@ -36,18 +38,30 @@ impl CompoundTag {
         Self { value: HashMap::new() }
     }
 
-    pub(crate) fn read(reader: &mut dyn NbtReader, tracker: &mut ReaderTracker) -> Result<Self> {
+    /// Pre-sizes the backing map, e.g. when the number of entries is known up front. Decoding
+    /// cannot use this because compounds are terminated by TAG_End rather than length-prefixed.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self { value: HashMap::with_capacity(capacity) }
+    }
+
+    pub fn builder() -> CompoundTagBuilder {
+        CompoundTagBuilder::new()
+    }
+
+    pub(crate) fn read(reader: &mut dyn NbtRead, tracker: &mut ReaderTracker) -> Result<Self> {
         let mut compound = CompoundTag::new();
         loop {
             let type_id = reader.read_byte()?;
             let tag_type = TagType::from_id(type_id)
-                .ok_or_else(|| NbtError::new_data_error(&format!("Invalid tag type ID in CompoundTag: {}", type_id)))?;
+                .ok_or(NbtError::InvalidTagType(type_id))?;
 
             if tag_type == TagType::End {
                 break;
             }
 
-            let name = reader.read_string()?;
+            let name = reader.read_string(tracker)?;
+            tracker.check_compound_entries(compound.value.len() + 1)?;
+            tracker.consume_bytes(std::mem::size_of::<(String, Box<dyn Tag>)>())?;
             let tag = tag::create_tag(tag_type, reader, tracker)?;
 
             compound.value.insert(name, tag);
@@ -76,6 +90,11 @@ impl CompoundTag {
         self.value.get_mut(name).map(|b| &mut **b)
     }
 
+    /// Mutable access to the stored box, so the tag can also be replaced by one of another type.
+    pub fn get_mut(&mut self, name: &str) -> Option<&mut Box<dyn Tag>> {
+        self.value.get_mut(name)
+    }
+
     fn get_typed_tag<T: Tag + 'static>(&self, name: &str) -> Result<Option<&T>> {
         match self.get_tag(name) {
             None => Ok(None),
@@ -95,6 +114,28 @@ impl CompoundTag {
         self.get_typed_tag(name)
     }
 
+    fn get_typed_tag_mut<T: Tag + 'static>(&mut self, name: &str) -> Result<Option<&mut T>> {
+        match self.value.get_mut(name) {
+            None => Ok(None),
+            Some(tag) => {
+                let found = tag.get_type().get_name();
+                tag.as_any_mut().downcast_mut::<T>()
+                    .ok_or_else(|| NbtError::new_unexpected_tag_type(&format!(
+                        "Expected tag '{}' to be type {}, but found {}",
+                        name, std::any::type_name::<T>(), found
+                    )))
+                    .map(Some)
+            }
+        }
+    }
+
+    pub fn get_list_mut(&mut self, name: &str) -> Result<Option<&mut ListTag>> {
+        self.get_typed_tag_mut(name)
+    }
+    pub fn get_compound_mut(&mut self, name: &str) -> Result<Option<&mut CompoundTag>> {
+        self.get_typed_tag_mut(name)
+    }
+
     // Simplified primitive getter using From impls defined below
     fn get_primitive_value<T, V>(&self, name: &str, default: Option<V>) -> Result<V>
     where
@@ -147,10 +188,21 @@ impl CompoundTag {
 
 
     // --- Iteration (Remain the same) ---
+    pub fn keys(&self) -> impl Iterator<Item = &str> { self.value.keys().map(|k| k.as_str()) }
     pub fn iter(&self) -> impl Iterator<Item = (&String, &dyn Tag)> { self.value.iter().map(|(k, v)| (k, &**v)) }
     pub fn iter_mut(&mut self) -> impl Iterator<Item = (&String, &mut dyn Tag)> { self.value.iter_mut().map(|(k, v)| (k, &mut **v)) }
 
     // --- Merging (Remains the same) ---
+    /// Compares like `==` but skips the keys in `ignore`, e.g. a block entity's `x`, `y`, `z`.
+    /// Only top-level keys are matched; nested compounds are compared in full.
+    pub fn equals_ignoring(&self, other: &CompoundTag, ignore: &[&str]) -> bool {
+        let kept = |name: &String| !ignore.contains(&name.as_str());
+        self.value.keys().filter(|k| kept(k)).count() == other.value.keys().filter(|k| kept(k)).count()
+            && self.value.iter()
+                .filter(|(k, _)| kept(k))
+                .all(|(k, v)| other.value.get(k).is_some_and(|o| v.equals(&**o)))
+    }
+
     pub fn merge(&self, other: &CompoundTag) -> CompoundTag {
         let mut new_compound = self.clone();
         for (k, v) in &other.value {
@@ -162,7 +214,7 @@ impl CompoundTag {
 
 impl Tag for CompoundTag {
     fn get_type(&self) -> TagType { TagType::Compound }
-    fn write(&self, writer: &mut dyn NbtWriter) -> Result<()> {
+    fn write(&self, writer: &mut dyn NbtWrite) -> Result<()> {
         for (name, tag) in &self.value {
             writer.write_byte(tag.get_type() as u8)?;
             writer.write_string(name)?;
@@ -192,6 +244,17 @@ impl Tag for CompoundTag {
     }
 }
 
+/// `compound["name"]` returns the tag stored under `name`.
+///
+/// # Panics
+/// Panics if there is no such tag, like `HashMap`'s `Index`. Use `get_tag` for fallible access.
+impl std::ops::Index<&str> for CompoundTag {
+    type Output = dyn Tag;
+    fn index(&self, name: &str) -> &Self::Output {
+        self.get_tag(name).unwrap_or_else(|| panic!("Tag \"{}\" does not exist", name))
+    }
+}
+
 impl Default for CompoundTag { fn default() -> Self { Self::new() } }
 
 // --- From Implementations (Moved to module scope below impl CompoundTag) ---
@@ -203,4 +266,45 @@ impl<'a> From<&'a FloatTag> for f32 { fn from(tag: &'a FloatTag) -> Self { tag.v
 impl<'a> From<&'a DoubleTag> for f64 { fn from(tag: &'a DoubleTag) -> Self { tag.value } }
 impl<'a> From<&'a ByteArrayTag> for Vec<u8> { fn from(tag: &'a ByteArrayTag) -> Self { tag.value.clone() } }
 impl<'a> From<&'a StringTag> for String { fn from(tag: &'a StringTag) -> Self { tag.value.clone() } }
-impl<'a> From<&'a IntArrayTag> for Vec<i32> { fn from(tag: &'a IntArrayTag) -> Self { tag.value.clone() } }
\ No newline at end of file
+impl<'a> From<&'a IntArrayTag> for Vec<i32> { fn from(tag: &'a IntArrayTag) -> Self { tag.value.clone() } }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn block_entity(x: i32, extra: Option<&str>) -> CompoundTag {
+        let mut tag = CompoundTag::new();
+        tag.set_string("id".to_string(), "Chest".to_string()).unwrap();
+        tag.set_int("x".to_string(), x).unwrap();
+        if let Some(key) = extra {
+            tag.set_int(key.to_string(), 1).unwrap();
+        }
+        tag
+    }
+
+    #[test]
+    fn equals_ignoring_skips_listed_keys() {
+        let a = block_entity(1, None);
+        let b = block_entity(2, None);
+        assert!(!a.equals(&b));
+        assert!(a.equals_ignoring(&b, &["x"]));
+        assert!(!a.equals_ignoring(&b, &["y"]));
+    }
+
+    #[test]
+    fn equals_ignoring_detects_missing_and_extra_keys() {
+        let base = block_entity(1, None);
+        let extra = block_entity(1, Some("Lock"));
+        assert!(!base.equals_ignoring(&extra, &["x"]));
+        assert!(!extra.equals_ignoring(&base, &["x"]));
+        // An ignored key may be present on one side only
+        assert!(base.equals_ignoring(&extra, &["Lock"]));
+        assert!(extra.equals_ignoring(&base, &["Lock"]));
+
+        // Same key count, different keys
+        let mut renamed = block_entity(1, None);
+        renamed.remove_tag("id");
+        renamed.set_string("name".to_string(), "Chest".to_string()).unwrap();
+        assert!(!base.equals_ignoring(&renamed, &[]));
+    }
+}
\ No newline at end of file
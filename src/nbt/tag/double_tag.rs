@@ -1,12 +1,14 @@
 // src/nbt/tag/double_tag.rs
 #![allow(dead_code)]
 
-use crate::nbt::error::Result;
-use crate::nbt::serializer::{NbtReader, NbtWriter}; // Removed NbtWrite
+use crate::nbt::error::{NbtError, Result};
+use crate::nbt::serializer::{NbtRead, NbtWrite};
 use crate::nbt::tag::tag::{Tag, TagType};
 use std::any::Any;
 use std::fmt;
 
+/// Same NaN policy as `FloatTag`: `new` is permissive, `new_finite` rejects NaN and infinities,
+/// and `bit_equals` is available when IEEE equality is not wanted.
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct DoubleTag {
     pub value: f64,
@@ -17,7 +19,22 @@ impl DoubleTag {
         Self { value }
     }
 
-    pub fn read(reader: &mut dyn NbtReader) -> Result<Self> {
+    pub fn new_finite(value: f64) -> Result<Self> {
+        if !value.is_finite() {
+            return Err(NbtError::new_invalid_tag_value(&format!("DoubleTag value must be finite, got {}", value)));
+        }
+        Ok(Self::new(value))
+    }
+
+    pub fn value(&self) -> f64 {
+        self.value
+    }
+
+    pub fn bit_equals(&self, other: &DoubleTag) -> bool {
+        self.value.to_bits() == other.value.to_bits()
+    }
+
+    pub fn read(reader: &mut dyn NbtRead) -> Result<Self> {
         Ok(Self::new(reader.read_double()?))
     }
 }
@@ -27,7 +44,7 @@ impl Tag for DoubleTag {
         TagType::Double
     }
 
-    fn write(&self, writer: &mut dyn NbtWriter) -> Result<()> {
+    fn write(&self, writer: &mut dyn NbtWrite) -> Result<()> {
         writer.write_double(self.value)
     }
 
@@ -54,4 +71,30 @@ impl Tag for DoubleTag {
     fn fmt_pretty(&self, f: &mut fmt::Formatter<'_>, _indentation: usize) -> fmt::Result {
         write!(f, "TAG_Double: {}", self.value)
     }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn equals_follows_ieee_and_bit_equals_compares_encoding() {
+        let nan = DoubleTag::new(f64::NAN);
+        assert!(!nan.equals(&nan));
+        assert!(nan.bit_equals(&nan));
+
+        let zero = DoubleTag::new(0.0);
+        let negative_zero = DoubleTag::new(-0.0);
+        assert!(zero.equals(&negative_zero));
+        assert!(!zero.bit_equals(&negative_zero));
+        assert_eq!(negative_zero.value(), 0.0);
+    }
+
+    #[test]
+    fn new_finite_rejects_nan_and_infinities() {
+        for value in [f64::NAN, f64::INFINITY, f64::NEG_INFINITY] {
+            assert!(DoubleTag::new_finite(value).is_err());
+        }
+        assert_eq!(DoubleTag::new_finite(1.5).unwrap().value(), 1.5);
+    }
 }
\ No newline at end of file
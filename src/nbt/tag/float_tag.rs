@@ -1,13 +1,15 @@
 // src/nbt/tag/float_tag.rs
 #![allow(dead_code)]
 
-use crate::nbt::error::Result;
-use crate::nbt::serializer::{NbtReader, NbtWriter}; // Removed NbtWrite
+use crate::nbt::error::{NbtError, Result};
+use crate::nbt::serializer::{NbtRead, NbtWrite};
 use crate::nbt::tag::tag::{Tag, TagType};
-use crate::utils;
 use std::any::Any;
 use std::fmt;
 
+/// `new` accepts any `f32`, including NaN and infinities, which cannot be represented in text
+/// formats such as SNBT or JSON. Use `new_finite` where that matters. Equality follows IEEE 754
+/// (NaN is never equal to itself); `bit_equals` compares the exact encoding instead.
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct FloatTag {
     pub value: f32,
@@ -18,7 +20,23 @@ impl FloatTag {
         Self { value }
     }
 
-    pub fn read(reader: &mut dyn NbtReader) -> Result<Self> {
+    pub fn new_finite(value: f32) -> Result<Self> {
+        if !value.is_finite() {
+            return Err(NbtError::new_invalid_tag_value(&format!("FloatTag value must be finite, got {}", value)));
+        }
+        Ok(Self::new(value))
+    }
+
+    pub fn value(&self) -> f32 {
+        self.value
+    }
+
+    /// Exact comparison of the stored bits: NaN payloads match and 0.0 differs from -0.0.
+    pub fn bit_equals(&self, other: &FloatTag) -> bool {
+        self.value.to_bits() == other.value.to_bits()
+    }
+
+    pub fn read(reader: &mut dyn NbtRead) -> Result<Self> {
         Ok(Self::new(reader.read_float()?))
     }
 }
@@ -28,7 +46,7 @@ impl Tag for FloatTag {
         TagType::Float
     }
 
-    fn write(&self, writer: &mut dyn NbtWriter) -> Result<()> {
+    fn write(&self, writer: &mut dyn NbtWrite) -> Result<()> {
         writer.write_float(self.value)
     }
 
@@ -37,11 +55,7 @@ impl Tag for FloatTag {
     }
 
     fn equals(&self, other: &dyn Tag) -> bool {
-        other.as_any().downcast_ref::<FloatTag>().map_or(false, |t| {
-            let self_bytes = utils::binary::write_float(self.value).unwrap_or_default();
-            let other_bytes = utils::binary::write_float(t.value).unwrap_or_default();
-            self_bytes == other_bytes
-        })
+        other.as_any().downcast_ref::<FloatTag>().map_or(false, |t| self.value == t.value)
     }
 
     fn clone_tag(&self) -> Box<dyn Tag> {
@@ -59,4 +73,30 @@ impl Tag for FloatTag {
     fn fmt_pretty(&self, f: &mut fmt::Formatter<'_>, _indentation: usize) -> fmt::Result {
         write!(f, "TAG_Float: {}", self.value)
     }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn equals_follows_ieee_and_bit_equals_compares_encoding() {
+        let nan = FloatTag::new(f32::NAN);
+        assert!(!nan.equals(&nan));
+        assert!(nan.bit_equals(&nan));
+
+        let zero = FloatTag::new(0.0);
+        let negative_zero = FloatTag::new(-0.0);
+        assert!(zero.equals(&negative_zero));
+        assert!(!zero.bit_equals(&negative_zero));
+        assert_eq!(negative_zero.value(), 0.0);
+    }
+
+    #[test]
+    fn new_finite_rejects_nan_and_infinities() {
+        for value in [f32::NAN, f32::INFINITY, f32::NEG_INFINITY] {
+            assert!(FloatTag::new_finite(value).is_err());
+        }
+        assert_eq!(FloatTag::new_finite(1.5).unwrap().value(), 1.5);
+    }
 }
\ No newline at end of file
@@ -2,7 +2,8 @@
 #![allow(dead_code)]
 
 use crate::nbt::error::Result;
-use crate::nbt::serializer::{NbtReader, NbtWriter}; // Removed NbtWrite
+use crate::nbt::reader_tracker::ReaderTracker;
+use crate::nbt::serializer::{NbtRead, NbtWrite};
 use crate::nbt::tag::tag::{Tag, TagType};
 use std::any::Any;
 use std::fmt;
@@ -17,8 +18,8 @@ impl IntArrayTag {
         Self { value }
     }
 
-    pub fn read(reader: &mut dyn NbtReader) -> Result<Self> {
-        Ok(Self::new(reader.read_int_array()?))
+    pub fn read(reader: &mut dyn NbtRead, tracker: &mut ReaderTracker) -> Result<Self> {
+        Ok(Self::new(reader.read_int_array(tracker)?))
     }
 }
 
@@ -27,7 +28,7 @@ impl Tag for IntArrayTag {
         TagType::IntArray
     }
 
-    fn write(&self, writer: &mut dyn NbtWriter) -> Result<()> {
+    fn write(&self, writer: &mut dyn NbtWrite) -> Result<()> {
         writer.write_int_array(&self.value)
     }
 
@@ -2,14 +2,18 @@
 #![allow(dead_code)]
 
 use crate::nbt::error::{NbtError, Result};
-use crate::nbt::serializer::{NbtReader, NbtWriter};
+use crate::nbt::serializer::{NbtRead, NbtWrite};
 use crate::nbt::tag::tag::{Tag, TagType};
 use crate::nbt::reader_tracker::ReaderTracker;
-use crate::nbt::tag;
+use crate::nbt::tag::{self, CompoundTag, DoubleTag, IntTag, ListTagBuilder, StringTag};
 use std::any::Any;
 use std::fmt;
 use std::convert::TryInto;
 
+// Upper bound on element slots reserved ahead of reading a list, since the declared size is only
+// checked against the input as elements are read
+const MAX_LIST_PREALLOC: usize = 4096;
+
 #[derive(Debug, Clone)]
 pub struct ListTag {
     value: Vec<Box<dyn Tag>>,
@@ -29,25 +33,35 @@ impl ListTag {
         Self { value: Vec::new(), tag_type }
     }
 
-    pub(crate) fn read(reader: &mut dyn NbtReader, tracker: &mut ReaderTracker) -> Result<Self> {
+    pub fn with_capacity(tag_type: TagType, capacity: usize) -> Self {
+        Self { value: Vec::with_capacity(capacity), tag_type }
+    }
+
+    pub fn builder(tag_type: TagType) -> ListTagBuilder {
+        ListTagBuilder::new(tag_type)
+    }
+
+    pub(crate) fn read(reader: &mut dyn NbtRead, tracker: &mut ReaderTracker) -> Result<Self> {
         let tag_type_id = reader.read_byte()?;
         let size = reader.read_int()?;
 
         let tag_type = TagType::from_id(tag_type_id)
-            .ok_or_else(|| NbtError::new_data_error(&format!("Invalid tag type ID in ListTag: {}", tag_type_id)))?;
+            .ok_or(NbtError::InvalidTagType(tag_type_id))?;
 
         if size < 0 {
             return Err(NbtError::new_data_error(&format!("Invalid negative size for ListTag: {}", size)));
         }
         let usize_size: usize = size.try_into().map_err(|_| NbtError::new_data_error("ListTag size too large"))?;
 
-        let mut list = ListTag::new(tag_type);
+        if usize_size > 0 && tag_type == TagType::End {
+            return Err(NbtError::new_data_error("Unexpected non-empty list of TAG_End"));
+        }
+        // Validate the declared size before trusting it for the allocation
+        tracker.check_list_length(usize_size)?;
+        tracker.consume_bytes(usize_size.saturating_mul(std::mem::size_of::<Box<dyn Tag>>()))?;
+        let mut list = ListTag::with_capacity(tag_type, usize_size.min(MAX_LIST_PREALLOC));
 
         if usize_size > 0 {
-            if tag_type == TagType::End {
-                return Err(NbtError::new_data_error("Unexpected non-empty list of TAG_End"));
-            }
-            list.value.reserve(usize_size);
             // Depth is managed by caller
             for _ in 0..usize_size {
                 let element = tag::create_tag(tag_type, reader, tracker)?;
@@ -129,6 +143,33 @@ impl ListTag {
         self.value.get_mut(index).map(|b| &mut **b)
     }
 
+    // Returns the element at `index` if it exists and is a `T`.
+    fn get_typed<T: Tag + 'static>(&self, index: usize) -> Option<&T> {
+        self.get(index)?.as_any().downcast_ref::<T>()
+    }
+
+    pub fn get_int(&self, index: usize) -> Option<i32> {
+        self.get_typed::<IntTag>(index).map(|t| t.value)
+    }
+
+    pub fn get_double(&self, index: usize) -> Option<f64> {
+        self.get_typed::<DoubleTag>(index).map(|t| t.value)
+    }
+
+    pub fn get_string(&self, index: usize) -> Option<&str> {
+        self.get_typed::<StringTag>(index).map(|t| t.value.as_str())
+    }
+
+    pub fn get_compound(&self, index: usize) -> Option<&CompoundTag> {
+        self.get_typed::<CompoundTag>(index)
+    }
+
+    /// Iterates the elements of a list of compounds (inventories, entities, ...).
+    /// Yields nothing if the list holds another tag type.
+    pub fn as_compounds(&self) -> impl Iterator<Item = &CompoundTag> {
+        self.value.iter().filter_map(|t| t.as_any().downcast_ref::<CompoundTag>())
+    }
+
     pub fn set(&mut self, index: usize, tag: Box<dyn Tag>) -> Result<()> {
         self.check_tag_type(&*tag)?;
         if index >= self.len() {
@@ -153,7 +194,7 @@ impl Tag for ListTag {
         TagType::List
     }
 
-    fn write(&self, writer: &mut dyn NbtWriter) -> Result<()> {
+    fn write(&self, writer: &mut dyn NbtWrite) -> Result<()> {
         writer.write_byte(self.tag_type as u8)?;
         let len: i32 = self.value.len().try_into().map_err(|_| NbtError::new_data_error("ListTag size too large for i32"))?;
         writer.write_int(len)?;
@@ -193,4 +234,59 @@ impl Tag for ListTag {
         }
         write!(f, "{}}}", " ".repeat(indentation * 2))
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nbt::big_endian_serializer::BigEndianNbtSerializer;
+    use crate::nbt::reader_tracker::ReaderLimits;
+
+    #[test]
+    fn forged_length_is_not_preallocated() {
+        // Root list of compounds with an empty name, declaring i32::MAX elements and holding none
+        let data = [9, 0, 0, 10, 0x7F, 0xFF, 0xFF, 0xFF];
+        let err = BigEndianNbtSerializer::from_bytes(&data)
+            .read_with_limits(0, ReaderLimits::unlimited())
+            .unwrap_err();
+        assert!(matches!(err, NbtError::UnexpectedEof(_)), "{:?}", err);
+    }
+
+    #[test]
+    fn typed_getters_match_element_type() {
+        let mut ints = ListTag::new(TagType::Int);
+        ints.push(Box::new(IntTag::new(7))).unwrap();
+        assert_eq!(ints.get_int(0), Some(7));
+        assert_eq!(ints.get_int(1), None);
+        assert_eq!(ints.get_double(0), None);
+        assert_eq!(ints.get_string(0), None);
+        assert!(ints.get_compound(0).is_none());
+
+        let mut strings = ListTag::new(TagType::String);
+        strings.push(Box::new(StringTag::new("stone".to_string()))).unwrap();
+        assert_eq!(strings.get_string(0), Some("stone"));
+        assert_eq!(strings.get_int(0), None);
+
+        let mut doubles = ListTag::new(TagType::Double);
+        doubles.push(Box::new(DoubleTag::new(0.5))).unwrap();
+        assert_eq!(doubles.get_double(0), Some(0.5));
+        assert_eq!(doubles.get_int(0), None);
+    }
+
+    #[test]
+    fn as_compounds_yields_only_compound_lists() {
+        let mut first = CompoundTag::new();
+        first.set_int("slot".to_string(), 0).unwrap();
+        let mut second = CompoundTag::new();
+        second.set_int("slot".to_string(), 1).unwrap();
+        let mut items = ListTag::new(TagType::Compound);
+        items.push(Box::new(first.clone())).unwrap();
+        items.push(Box::new(second.clone())).unwrap();
+        assert_eq!(items.as_compounds().collect::<Vec<_>>(), [&first, &second]);
+        assert_eq!(items.get_compound(1), Some(&second));
+
+        let mut ints = ListTag::new(TagType::Int);
+        ints.push(Box::new(IntTag::new(1))).unwrap();
+        assert_eq!(ints.as_compounds().count(), 0);
+    }
+}
@@ -1,6 +1,7 @@
 // src/nbt/tag/mod.rs
 #![allow(dead_code)]
 
+mod builder;
 mod byte_array_tag;
 mod byte_tag;
 mod compound_tag;
@@ -15,6 +16,7 @@ mod string_tag;
 pub mod tag;
 
 // Re-export concrete tag types and the base trait/enum
+pub use builder::{CompoundTagBuilder, ListTagBuilder};
 pub use byte_array_tag::ByteArrayTag;
 pub use byte_tag::ByteTag;
 pub use compound_tag::CompoundTag;
@@ -29,7 +31,7 @@ pub use string_tag::StringTag;
 pub use tag::{Tag, TagType};
 
 use crate::nbt::error::{NbtError, Result};
-use crate::nbt::serializer::NbtReader;
+use crate::nbt::serializer::NbtRead;
 use crate::nbt::reader_tracker::ReaderTracker;
 use std::boxed::Box;
 use std::fmt; // Keep fmt for Display macro
@@ -38,16 +40,16 @@ use std::fmt; // Keep fmt for Display macro
 
 
 // Factory function equivalent to NBT::createTag (remains the same logic)
-pub fn create_tag(tag_type: TagType, reader: &mut dyn NbtReader, tracker: &mut ReaderTracker) -> Result<Box<dyn Tag>> {
-    match tag_type {
+pub fn create_tag(tag_type: TagType, reader: &mut dyn NbtRead, tracker: &mut ReaderTracker) -> Result<Box<dyn Tag>> {
+    let tag = match tag_type {
         TagType::Byte => ByteTag::read(reader).map(|t| Box::new(t) as Box<dyn Tag>),
         TagType::Short => ShortTag::read(reader).map(|t| Box::new(t) as Box<dyn Tag>),
         TagType::Int => IntTag::read(reader).map(|t| Box::new(t) as Box<dyn Tag>),
         TagType::Long => LongTag::read(reader).map(|t| Box::new(t) as Box<dyn Tag>),
         TagType::Float => FloatTag::read(reader).map(|t| Box::new(t) as Box<dyn Tag>),
         TagType::Double => DoubleTag::read(reader).map(|t| Box::new(t) as Box<dyn Tag>),
-        TagType::ByteArray => ByteArrayTag::read(reader).map(|t| Box::new(t) as Box<dyn Tag>),
-        TagType::String => StringTag::read(reader).map(|t| Box::new(t) as Box<dyn Tag>),
+        TagType::ByteArray => ByteArrayTag::read(reader, tracker).map(|t| Box::new(t) as Box<dyn Tag>),
+        TagType::String => StringTag::read(reader, tracker).map(|t| Box::new(t) as Box<dyn Tag>),
         TagType::List => {
             tracker.increase_depth()?;
             let result = ListTag::read(reader, tracker);
@@ -60,9 +62,13 @@ pub fn create_tag(tag_type: TagType, reader: &mut dyn NbtReader, tracker: &mut R
             tracker.decrease_depth();
             result.map(|t| Box::new(t) as Box<dyn Tag>)
         },
-        TagType::IntArray => IntArrayTag::read(reader).map(|t| Box::new(t) as Box<dyn Tag>),
+        TagType::IntArray => IntArrayTag::read(reader, tracker).map(|t| Box::new(t) as Box<dyn Tag>),
         TagType::End => Err(NbtError::new_data_error("Cannot create TagType::End")),
-    }
+    }?;
+    // Array and string payloads were charged by the reader before allocating; list and compound
+    // storage is charged as it is read
+    tracker.consume_bytes(std::mem::size_of_val(&*tag))?;
+    Ok(tag)
 }
 
 // Define the Display macro here
@@ -2,7 +2,8 @@
 #![allow(dead_code)]
 
 use crate::nbt::error::{NbtError, Result};
-use crate::nbt::serializer::{NbtReader, NbtWriter}; // Removed NbtWrite
+use crate::nbt::reader_tracker::ReaderTracker;
+use crate::nbt::serializer::{NbtRead, NbtWrite};
 use crate::nbt::tag::tag::{Tag, TagType};
 use crate::utils::limits;
 use std::any::Any;
@@ -19,8 +20,8 @@ impl StringTag {
         Self { value }
     }
 
-    pub fn read(reader: &mut dyn NbtReader) -> Result<Self> {
-        Ok(Self::new(reader.read_string()?))
+    pub fn read(reader: &mut dyn NbtRead, tracker: &mut ReaderTracker) -> Result<Self> {
+        Ok(Self::new(reader.read_string(tracker)?))
     }
 
     pub(crate) fn check_write_length(value: &str) -> Result<i16> {
@@ -41,7 +42,7 @@ impl Tag for StringTag {
         TagType::String
     }
 
-    fn write(&self, writer: &mut dyn NbtWriter) -> Result<()> {
+    fn write(&self, writer: &mut dyn NbtWrite) -> Result<()> {
         writer.write_string(&self.value)
     }
 
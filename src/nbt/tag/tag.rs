@@ -2,7 +2,7 @@
 #![allow(dead_code)]
 
 use crate::nbt::error::{NbtError, Result};
-use crate::nbt::serializer::NbtWriter;
+use crate::nbt::serializer::NbtWrite;
 use std::fmt::{Debug};
 use std::any::Any;
 
@@ -39,7 +39,7 @@ impl TagType {
 // Base trait for all NBT tags
 pub trait Tag: Any + Debug + Send + Sync {
     fn get_type(&self) -> TagType;
-    fn write(&self, writer: &mut dyn NbtWriter) -> Result<()>;
+    fn write(&self, writer: &mut dyn NbtWrite) -> Result<()>;
     fn get_value(&self) -> Box<dyn Any + Send + Sync>;
     fn equals(&self, other: &dyn Tag) -> bool;
     fn clone_tag(&self) -> Box<dyn Tag>;
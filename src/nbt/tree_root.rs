@@ -6,6 +6,8 @@ use crate::nbt::tag::{Tag, CompoundTag};
 use crate::utils::limits;
 use std::fmt;
 
+/// A root tag together with its name. Disk NBT usually carries a name, while Bedrock network
+/// NBT uses an empty one; both are written the same way, as a zero-length name string.
 #[derive(Debug, Clone)]
 pub struct TreeRoot {
     name: String,
@@ -23,7 +25,14 @@ impl TreeRoot {
         Ok(Self { name, root })
     }
 
-    pub fn get_name(&self) -> &str { &self.name }
+    /// Root with an empty name, as used by network NBT.
+    pub fn nameless(root: Box<dyn Tag>) -> Self {
+        Self { name: String::new(), root }
+    }
+
+    pub fn name(&self) -> &str { &self.name }
+    pub fn get_name(&self) -> &str { self.name() }
+    pub fn is_nameless(&self) -> bool { self.name.is_empty() }
     pub fn get_tag(&self) -> &dyn Tag { &*self.root }
     pub fn get_tag_mut(&mut self) -> &mut dyn Tag { &mut *self.root }
 
@@ -60,4 +69,74 @@ impl fmt::Display for TreeRoot {
         writeln!(f)?;
         write!(f, "}}")
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nbt::big_endian_serializer::BigEndianNbtSerializer;
+    use crate::nbt::io_serializer::{IoNbtReader, IoNbtWriter};
+    use crate::nbt::little_endian_serializer::LittleEndianNbtSerializer;
+    use crate::nbt::reader_tracker::ReaderLimits;
+    use byteorder::{BigEndian, LittleEndian};
+    use std::io::Cursor;
+
+    fn sample(name: &str) -> TreeRoot {
+        let mut compound = CompoundTag::new();
+        compound.set_int("x".to_string(), 7).unwrap();
+        TreeRoot::new(name.to_string(), Box::new(compound)).unwrap()
+    }
+
+    // Writes `root` with every serializer and checks that each reads back the same name and tag
+    fn assert_round_trips(root: &TreeRoot) {
+        let bytes = BigEndianNbtSerializer::write_to_bytes(root).unwrap();
+        assert_eq!(&BigEndianNbtSerializer::read_from_buffer(&bytes, 0).unwrap(), root);
+
+        let bytes = LittleEndianNbtSerializer::write_to_bytes(root).unwrap();
+        assert_eq!(&LittleEndianNbtSerializer::read_from_buffer(&bytes, 0).unwrap(), root);
+
+        let mut bytes = Vec::new();
+        IoNbtWriter::<_, BigEndian>::new(&mut bytes).write_root(root).unwrap();
+        let decoded = IoNbtReader::<_, BigEndian>::new(&mut Cursor::new(bytes))
+            .read_root(0, ReaderLimits::default())
+            .unwrap();
+        assert_eq!(&decoded, root);
+
+        let mut bytes = Vec::new();
+        IoNbtWriter::<_, LittleEndian>::new(&mut bytes).write_root(root).unwrap();
+        let decoded = IoNbtReader::<_, LittleEndian>::new(&mut Cursor::new(bytes))
+            .read_root(0, ReaderLimits::default())
+            .unwrap();
+        assert_eq!(&decoded, root);
+    }
+
+    #[test]
+    fn nameless_root_is_written_with_an_empty_name() {
+        let root = TreeRoot::nameless(Box::new(CompoundTag::new()));
+        assert!(root.is_nameless());
+        let bytes = LittleEndianNbtSerializer::write_to_bytes(&root).unwrap();
+        // Compound type, zero-length name, TAG_End
+        assert_eq!(bytes, [0x0A, 0x00, 0x00, 0x00]);
+        let decoded = LittleEndianNbtSerializer::read_from_buffer(&bytes, 0).unwrap();
+        assert!(decoded.is_nameless());
+        assert_eq!(decoded, root);
+    }
+
+    #[test]
+    fn named_and_nameless_roots_round_trip() {
+        let named = sample("level");
+        assert_eq!(named.name(), "level");
+        assert_round_trips(&named);
+
+        let nameless = TreeRoot::nameless(sample("").get_tag().clone_tag());
+        assert_eq!(nameless.name(), "");
+        assert_round_trips(&nameless);
+    }
+
+    #[test]
+    fn new_rejects_overlong_names() {
+        let name = "a".repeat(limits::I16_MAX as usize + 1);
+        assert!(TreeRoot::new(name, Box::new(CompoundTag::new())).is_err());
+        assert!(!TreeRoot::new("root".to_string(), Box::new(CompoundTag::new())).unwrap().is_nameless());
+    }
+}
@@ -14,7 +14,7 @@ pub const SIZEOF_DOUBLE: usize = 8;
 #[inline]
 fn check_length(bytes: &[u8], needed: usize) -> Result<()> {
     if bytes.len() < needed {
-        Err(BinaryDataException::new(format!(
+        Err(BinaryDataException::unexpected_eof(format!(
             "Not enough bytes: need {}, have {}",
             needed,
             bytes.len()
@@ -54,6 +54,37 @@ pub fn unsign_int(value: i64) -> u32 {
     value as u32
 }
 
+fn checked_cast<T: TryFrom<i64>>(value: i64, type_name: &str) -> Result<T> {
+    T::try_from(value).map_err(|_| BinaryDataException::new(format!("Value {} is out of range for {}", value, type_name)))
+}
+
+// Checked counterparts of the casts above: they fail instead of wrapping when `value` is
+// outside the target type's range, e.g. a negative length passed to an unsigned field
+
+pub fn try_sign_byte(value: i64) -> Result<i8> {
+    checked_cast(value, "i8")
+}
+
+pub fn try_unsign_byte(value: i64) -> Result<u8> {
+    checked_cast(value, "u8")
+}
+
+pub fn try_sign_short(value: i64) -> Result<i16> {
+    checked_cast(value, "i16")
+}
+
+pub fn try_unsign_short(value: i64) -> Result<u16> {
+    checked_cast(value, "u16")
+}
+
+pub fn try_sign_int(value: i64) -> Result<i32> {
+    checked_cast(value, "i32")
+}
+
+pub fn try_unsign_int(value: i64) -> Result<u32> {
+    checked_cast(value, "u32")
+}
+
 #[inline]
 pub fn flip_short_endianness(value: u16) -> u16 {
     value.swap_bytes()
@@ -322,7 +353,7 @@ pub fn read_unsigned_var_int(buffer: &[u8], offset: &mut usize) -> Result<u32> {
     for i in 0..5 {
         if *offset >= buffer.len() {
             *offset = initial_offset;
-            return Err(BinaryDataException::from_str("No bytes left in buffer"));
+            return Err(BinaryDataException::unexpected_eof("No bytes left in buffer".to_string()));
         }
         let byte = buffer[*offset];
         *offset += 1;
@@ -335,20 +366,15 @@ pub fn read_unsigned_var_int(buffer: &[u8], offset: &mut usize) -> Result<u32> {
     Err(BinaryDataException::from_str("VarInt did not terminate after 5 bytes!"))
 }
 
+/// Encodes an unsigned VarInt. Every `u32` fits in 5 bytes, so this cannot fail.
 pub fn write_unsigned_var_int(mut value: u32) -> Vec<u8> {
     let mut buf = Vec::with_capacity(5);
-    loop {
-        if (value & !0x7F) == 0 {
-            buf.push(value as u8);
-            return buf;
-        }
+    while (value & !0x7F) != 0 {
         buf.push(((value & 0x7F) | 0x80) as u8);
         value >>= 7;
-        if buf.len() >= 5 {
-            // In PM, this would panic. Consider returning Err instead.
-            panic!("Value {} too large to be encoded as a VarInt", value);
-        }
     }
+    buf.push(value as u8);
+    buf
 }
 
 pub fn read_var_int(buffer: &[u8], offset: &mut usize) -> Result<i32> {
@@ -367,7 +393,7 @@ pub fn read_unsigned_var_long(buffer: &[u8], offset: &mut usize) -> Result<u64>
     for i in 0..10 {
         if *offset >= buffer.len() {
             *offset = initial_offset;
-            return Err(BinaryDataException::from_str("No bytes left in buffer"));
+            return Err(BinaryDataException::unexpected_eof("No bytes left in buffer".to_string()));
         }
         let byte = buffer[*offset];
         *offset += 1;
@@ -380,20 +406,15 @@ pub fn read_unsigned_var_long(buffer: &[u8], offset: &mut usize) -> Result<u64>
     Err(BinaryDataException::from_str("VarLong did not terminate after 10 bytes!"))
 }
 
+/// Encodes an unsigned VarLong. Every `u64` fits in 10 bytes, so this cannot fail.
 pub fn write_unsigned_var_long(mut value: u64) -> Vec<u8> {
     let mut buf = Vec::with_capacity(10);
-    loop {
-        if (value & !0x7F) == 0 {
-            buf.push(value as u8);
-            return buf;
-        }
+    while (value & !0x7F) != 0 {
         buf.push(((value & 0x7F) | 0x80) as u8);
         value >>= 7;
-        if buf.len() >= 10 {
-            // In PM, this would panic. Consider returning Err instead.
-            panic!("Value {} too large to be encoded as a VarLong", value);
-        }
     }
+    buf.push(value as u8);
+    buf
 }
 
 pub fn read_var_long(buffer: &[u8], offset: &mut usize) -> Result<i64> {
@@ -404,4 +425,86 @@ pub fn read_var_long(buffer: &[u8], offset: &mut usize) -> Result<i64> {
 
 pub fn write_var_long(value: i64) -> Vec<u8> {
     write_unsigned_var_long(((value << 1) ^ (value >> 63)) as u64)
+}
+
+// Multiplier from rustc's FxHasher
+const HASH_SEED: u64 = 0x51_7c_c1_b7_27_22_0a_95;
+
+#[inline]
+fn hash_word(hash: u64, word: u64) -> u64 {
+    (hash.rotate_left(5) ^ word).wrapping_mul(HASH_SEED)
+}
+
+/// Fast non-cryptographic hash (FxHash-style) for dedup caches and tooling. Not collision
+/// resistant and not stable across crate versions: never persist it or use it for security.
+pub fn hash_bytes(bytes: &[u8]) -> u64 {
+    let mut hash = hash_word(0, bytes.len() as u64);
+    let mut chunks = bytes.chunks_exact(8);
+    for chunk in &mut chunks {
+        hash = hash_word(hash, u64::from_le_bytes(chunk.try_into().unwrap()));
+    }
+    let mut tail = [0u8; 8];
+    let rest = chunks.remainder();
+    if !rest.is_empty() {
+        tail[..rest.len()].copy_from_slice(rest);
+        hash = hash_word(hash, u64::from_le_bytes(tail));
+    }
+    hash
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn var_int_extremes_round_trip_within_max_length() {
+        for value in [0, 1, 0x7F, 0x80, u32::MAX] {
+            let bytes = write_unsigned_var_int(value);
+            assert!(bytes.len() <= 5);
+            assert_eq!(read_unsigned_var_int(&bytes, &mut 0).unwrap(), value);
+        }
+        for value in [0, -1, i32::MIN, i32::MAX] {
+            assert_eq!(read_var_int(&write_var_int(value), &mut 0).unwrap(), value);
+        }
+        assert_eq!(write_unsigned_var_int(u32::MAX), vec![0xFF, 0xFF, 0xFF, 0xFF, 0x0F]);
+    }
+
+    #[test]
+    fn var_long_extremes_round_trip_within_max_length() {
+        for value in [0, 0x7F, 0x80, u64::MAX] {
+            let bytes = write_unsigned_var_long(value);
+            assert!(bytes.len() <= 10);
+            assert_eq!(read_unsigned_var_long(&bytes, &mut 0).unwrap(), value);
+        }
+        for value in [0, -1, i64::MIN, i64::MAX] {
+            assert_eq!(read_var_long(&write_var_long(value), &mut 0).unwrap(), value);
+        }
+        assert_eq!(write_unsigned_var_long(u64::MAX).len(), 10);
+    }
+
+    #[test]
+    fn try_sign_accepts_exact_bounds() {
+        assert_eq!(try_sign_byte(i8::MIN as i64).unwrap(), i8::MIN);
+        assert_eq!(try_sign_byte(i8::MAX as i64).unwrap(), i8::MAX);
+        assert_eq!(try_unsign_byte(u8::MAX as i64).unwrap(), u8::MAX);
+        assert_eq!(try_unsign_byte(0).unwrap(), 0);
+        assert_eq!(try_sign_short(i16::MIN as i64).unwrap(), i16::MIN);
+        assert_eq!(try_unsign_short(u16::MAX as i64).unwrap(), u16::MAX);
+        assert_eq!(try_sign_int(i32::MIN as i64).unwrap(), i32::MIN);
+        assert_eq!(try_unsign_int(u32::MAX as i64).unwrap(), u32::MAX);
+    }
+
+    #[test]
+    fn try_sign_rejects_one_past_bounds() {
+        assert!(try_sign_byte(i8::MIN as i64 - 1).is_err());
+        assert!(try_sign_byte(i8::MAX as i64 + 1).is_err());
+        assert!(try_unsign_byte(u8::MAX as i64 + 1).is_err());
+        assert!(try_unsign_byte(-1).is_err());
+        assert!(try_sign_short(i16::MAX as i64 + 1).is_err());
+        assert!(try_unsign_short(u16::MAX as i64 + 1).is_err());
+        assert!(try_unsign_short(-1).is_err());
+        assert!(try_sign_int(i32::MIN as i64 - 1).is_err());
+        assert!(try_unsign_int(u32::MAX as i64 + 1).is_err());
+        assert!(try_unsign_int(-1).is_err());
+    }
 }
\ No newline at end of file
@@ -2,8 +2,13 @@
 #![allow(dead_code)]
 
 use crate::utils::binary;
+use crate::utils::binary_stream_ref::BinaryStreamRef;
 use crate::utils::error::{BinaryDataException, Result};
 use std::convert::TryInto;
+use std::net::{Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV4, SocketAddrV6};
+
+// AF_INET6 as sent by Bedrock clients (the Windows value); ignored when reading
+const AF_INET6: u16 = 23;
 
 #[derive(Debug, Clone, Default)]
 pub struct BinaryStream {
@@ -24,12 +29,28 @@ impl BinaryStream {
         Self { buffer: slice.to_vec(), offset: 0 }
     }
 
+    /// Read-only view of the buffer at the current offset, with its own cursor. Borrows instead
+    /// of copying, unlike `clone`; reads on the view do not move this stream.
+    pub fn fork(&self) -> BinaryStreamRef<'_> {
+        BinaryStreamRef::with_offset(&self.buffer, self.offset.min(self.buffer.len()))
+            .expect("offset is clamped to the buffer length")
+    }
+
     pub fn rewind(&mut self) {
         self.offset = 0;
     }
 
-    pub fn set_offset(&mut self, offset: usize) {
+    /// Moves the cursor to `offset`, which may be at most the buffer length.
+    pub fn set_offset(&mut self, offset: usize) -> Result<()> {
+        if offset > self.buffer.len() {
+            return Err(BinaryDataException::new(format!(
+                "Offset {} is out of bounds (buffer length {})",
+                offset,
+                self.buffer.len()
+            )));
+        }
         self.offset = offset;
+        Ok(())
     }
 
     pub fn get_offset(&self) -> usize {
@@ -44,6 +65,11 @@ impl BinaryStream {
         &mut self.buffer
     }
 
+    /// `binary::hash_bytes` over the whole buffer, regardless of the read offset.
+    pub fn content_hash(&self) -> u64 {
+        binary::hash_bytes(&self.buffer)
+    }
+
     #[inline]
     fn ensure_available(&self, len: usize) -> Result<()> {
         if len == 0 {
@@ -51,7 +77,7 @@ impl BinaryStream {
         }
         if self.offset.checked_add(len).is_none() || self.offset + len > self.buffer.len() {
             let remaining = self.buffer.len().saturating_sub(self.offset);
-            Err(BinaryDataException::new(format!(
+            Err(BinaryDataException::unexpected_eof(format!(
                 "Not enough bytes left in buffer: need {}, have {}",
                 len, remaining
             )))
@@ -67,6 +93,17 @@ impl BinaryStream {
         Ok(&self.buffer[start..self.offset])
     }
 
+    /// Borrows at most `len` bytes, fewer (possibly none) if the buffer ends first, and advances
+    /// past them. Use `get` when a field has a known size and a short read is an error.
+    pub fn get_up_to(&mut self, len: usize) -> &[u8] {
+        let start = self.offset.min(self.buffer.len());
+        let end = start + len.min(self.buffer.len() - start);
+        self.offset = end;
+        &self.buffer[start..end]
+    }
+
+    /// Borrows every unread byte and moves the offset to the end. No copy is made; call
+    /// `.to_vec()` on the result when an owned buffer is needed.
     pub fn get_remaining(&mut self) -> Result<&[u8]> {
         if self.offset >= self.buffer.len() {
             // Return empty slice instead of erroring if already at end
@@ -81,10 +118,41 @@ impl BinaryStream {
         }
     }
 
+    /// Number of unread bytes left after the current offset. Does not move the offset.
+    pub fn remaining(&self) -> usize {
+        self.buffer.len().saturating_sub(self.offset)
+    }
+
+    /// Returns the next `len` bytes without advancing the offset.
+    pub fn peek(&self, len: usize) -> Result<&[u8]> {
+        self.ensure_available(len)?;
+        Ok(&self.buffer[self.offset..self.offset + len])
+    }
+
+    /// Returns the next byte without advancing the offset.
+    pub fn peek_byte(&self) -> Result<u8> {
+        Ok(self.peek(1)?[0])
+    }
+
     pub fn put(&mut self, bytes: &[u8]) {
         self.buffer.extend_from_slice(bytes);
     }
 
+    /// Replaces already-written bytes starting at `pos`, e.g. to back-patch a length prefix.
+    /// Never extends the buffer; writing past the end is an error. The cursor is not moved.
+    pub fn overwrite_at(&mut self, pos: usize, bytes: &[u8]) -> Result<()> {
+        let end = pos.checked_add(bytes.len()).filter(|&end| end <= self.buffer.len()).ok_or_else(|| {
+            BinaryDataException::new(format!(
+                "Cannot overwrite {} bytes at offset {} (buffer length {})",
+                bytes.len(),
+                pos,
+                self.buffer.len()
+            ))
+        })?;
+        self.buffer[pos..end].copy_from_slice(bytes);
+        Ok(())
+    }
+
     pub fn get_bool(&mut self) -> Result<bool> {
         let byte = self.get_byte()?;
         Ok(byte != 0x00)
@@ -305,6 +373,23 @@ impl BinaryStream {
         Ok(())
     }
 
+    /// Reads a UUID in the Bedrock/Mojang layout: the most significant 64 bits followed by the
+    /// least significant 64 bits, each encoded as a little-endian long.
+    #[cfg(feature = "uuid")]
+    pub fn get_uuid(&mut self) -> Result<uuid::Uuid> {
+        let most_significant = self.get_unsigned_llong()?;
+        let least_significant = self.get_unsigned_llong()?;
+        Ok(uuid::Uuid::from_u64_pair(most_significant, least_significant))
+    }
+
+    /// Writes a UUID in the same layout as `get_uuid`.
+    #[cfg(feature = "uuid")]
+    pub fn put_uuid(&mut self, v: &uuid::Uuid) -> Result<()> {
+        let (most_significant, least_significant) = v.as_u64_pair();
+        self.put_unsigned_llong(most_significant)?;
+        self.put_unsigned_llong(least_significant)
+    }
+
     pub fn get_unsigned_var_int(&mut self) -> Result<u32> {
         self.ensure_available(1)?;
         let mut temp_offset = self.offset;
@@ -365,19 +450,300 @@ impl BinaryStream {
         self.put(&bytes);
     }
 
+    // --- Bulk helpers: one bounds check and one cursor update per call ---
+
+    pub fn put_u32_slice_le(&mut self, values: &[u32]) {
+        self.buffer.reserve(values.len() * binary::SIZEOF_INT);
+        for v in values {
+            self.buffer.extend_from_slice(&v.to_le_bytes());
+        }
+    }
+
+    pub fn get_u32_slice_le(&mut self, count: usize) -> Result<Vec<u32>> {
+        let len = count.checked_mul(binary::SIZEOF_INT)
+            .ok_or_else(|| BinaryDataException::from_str("Requested u32 count is too large"))?;
+        let bytes = self.get(len)?;
+        Ok(bytes.chunks_exact(binary::SIZEOF_INT)
+            .map(|c| u32::from_le_bytes([c[0], c[1], c[2], c[3]]))
+            .collect())
+    }
+
+    pub fn put_i32_slice_le(&mut self, values: &[i32]) {
+        self.buffer.reserve(values.len() * binary::SIZEOF_INT);
+        for v in values {
+            self.buffer.extend_from_slice(&v.to_le_bytes());
+        }
+    }
+
+    pub fn get_i32_slice_le(&mut self, count: usize) -> Result<Vec<i32>> {
+        let len = count.checked_mul(binary::SIZEOF_INT)
+            .ok_or_else(|| BinaryDataException::from_str("Requested i32 count is too large"))?;
+        let bytes = self.get(len)?;
+        Ok(bytes.chunks_exact(binary::SIZEOF_INT)
+            .map(|c| i32::from_le_bytes([c[0], c[1], c[2], c[3]]))
+            .collect())
+    }
+
+    /// Writes each value as a consecutive signed (zigzag) VarInt.
+    pub fn put_var_int_slice(&mut self, values: &[i32]) {
+        for &v in values {
+            self.buffer.extend_from_slice(&binary::write_var_int(v));
+        }
+    }
+
+    /// Reads `count` consecutive signed VarInts. On error the offset is left unchanged.
+    pub fn get_var_int_slice(&mut self, count: usize) -> Result<Vec<i32>> {
+        // Every VarInt takes at least one byte, so this also bounds the allocation below
+        self.ensure_available(count)?;
+        let mut temp_offset = self.offset;
+        let mut values = Vec::with_capacity(count);
+        for _ in 0..count {
+            values.push(binary::read_var_int(&self.buffer, &mut temp_offset)?);
+        }
+        self.offset = temp_offset;
+        Ok(values)
+    }
+
+    /// Reads a socket address in RakNet's encoding: a version byte (4 or 6), then for IPv4 the
+    /// four octets bit-inverted and a big-endian port, or for IPv6 a little-endian address
+    /// family, big-endian port, flow info, the 16 address bytes and the scope id.
+    /// On error the offset is left unchanged.
+    pub fn get_address(&mut self) -> Result<SocketAddr> {
+        let start = self.offset;
+        let result = self.get_address_inner();
+        if result.is_err() {
+            self.offset = start;
+        }
+        result
+    }
+
+    fn get_address_inner(&mut self) -> Result<SocketAddr> {
+        match self.get_byte()? {
+            4 => {
+                let octets = self.get(4)?;
+                let ip = Ipv4Addr::new(!octets[0], !octets[1], !octets[2], !octets[3]);
+                let port = self.get_short()?;
+                Ok(SocketAddr::V4(SocketAddrV4::new(ip, port)))
+            }
+            6 => {
+                self.get_lshort()?; // Address family, platform-specific
+                let port = self.get_short()?;
+                let flow_info = self.get_unsigned_int()?;
+                let octets: [u8; 16] = self.get(16)?.try_into()
+                    .map_err(|_| BinaryDataException::from_str("Invalid IPv6 address length"))?;
+                let scope_id = self.get_unsigned_int()?;
+                Ok(SocketAddr::V6(SocketAddrV6::new(Ipv6Addr::from(octets), port, flow_info, scope_id)))
+            }
+            version => Err(BinaryDataException::new(format!("Unknown IP address version {}", version))),
+        }
+    }
+
+    /// Writes a socket address in the encoding read by `get_address`.
+    pub fn put_address(&mut self, address: &SocketAddr) -> Result<()> {
+        match address {
+            SocketAddr::V4(v4) => {
+                self.put_byte(4);
+                let octets = v4.ip().octets();
+                self.put(&[!octets[0], !octets[1], !octets[2], !octets[3]]);
+                self.put_short(v4.port())
+            }
+            SocketAddr::V6(v6) => {
+                self.put_byte(6);
+                self.put_lshort(AF_INET6)?;
+                self.put_short(v6.port())?;
+                self.put_unsigned_int(v6.flowinfo())?;
+                self.put(&v6.ip().octets());
+                self.put_unsigned_int(v6.scope_id())
+            }
+        }
+    }
+
     pub fn feof(&self) -> bool {
         self.offset >= self.buffer.len()
     }
 
+    /// Reads a string prefixed with its byte length as an unsigned VarInt (Bedrock style).
+    /// The length is checked against the bytes actually remaining before anything is copied,
+    /// so a bogus prefix cannot trigger a large allocation.
     pub fn read_string(&mut self) -> Result<String> {
         let len = self.get_unsigned_var_int()? as usize;
         let bytes = self.get(len)?;
         String::from_utf8(bytes.to_vec()).map_err(|e| BinaryDataException::new(e.to_string()))
     }
 
-    pub fn write_string(&mut self, v: &str) {
+    /// Writes a string prefixed with its byte length as an unsigned VarInt.
+    /// Fails if the string is longer than a VarInt length prefix can describe.
+    pub fn write_string(&mut self, v: &str) -> Result<()> {
         let bytes = v.as_bytes();
-        self.put_unsigned_var_int(bytes.len().try_into().unwrap_or(u32::MAX));
+        let len: u32 = bytes.len().try_into().map_err(|_| {
+            BinaryDataException::new(format!("String of {} bytes is too long to be written", bytes.len()))
+        })?;
+        self.put_unsigned_var_int(len);
         self.put(bytes);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn string_is_prefixed_with_var_int_length() {
+        // 200 UTF-8 bytes need a two-byte VarInt prefix
+        let text = "é".repeat(100);
+        let mut stream = BinaryStream::new();
+        stream.write_string(&text).unwrap();
+        assert_eq!(&stream.get_buffer()[..2], &[0xC8, 0x01]);
+        assert_eq!(stream.get_buffer().len(), 202);
+        assert_eq!(stream.read_string().unwrap(), text);
+        assert!(stream.feof());
+    }
+
+    #[test]
+    fn multi_kilobyte_strings_round_trip() {
+        // 5000 bytes fit a two-byte prefix, 20000 bytes need three
+        for (len, prefix) in [(5_000, &[0x88, 0x27][..]), (20_000, &[0xA0, 0x9C, 0x01][..])] {
+            let text: String = (0..len).map(|i| (b'a' + (i % 26) as u8) as char).collect();
+            let mut stream = BinaryStream::new();
+            stream.write_string(&text).unwrap();
+            assert_eq!(&stream.get_buffer()[..prefix.len()], prefix);
+            assert_eq!(stream.get_buffer().len(), prefix.len() + len);
+            assert_eq!(stream.read_string().unwrap(), text);
+            assert!(stream.feof());
+        }
+    }
+
+    #[test]
+    fn bogus_string_length_is_rejected() {
+        let mut stream = BinaryStream::new();
+        stream.put_unsigned_var_int(u32::MAX);
+        stream.put(b"short");
+        let err = stream.read_string().unwrap_err();
+        assert!(err.is_eof());
+    }
+
+    #[test]
+    fn overlong_string_length_prefix_is_rejected() {
+        // Six continuation bytes are more than any u32 VarInt may use
+        let mut stream = BinaryStream::from_slice(&[0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x01, b'a']);
+        assert!(stream.read_string().is_err());
+        assert_eq!(stream.get_offset(), 0);
+    }
+
+    #[test]
+    fn fixed_width_slices_round_trip() {
+        let mut stream = BinaryStream::new();
+        stream.put_u32_slice_le(&[0, 1, u32::MAX]);
+        stream.put_i32_slice_le(&[i32::MIN, -1, i32::MAX]);
+        assert_eq!(&stream.get_buffer()[4..8], &[1, 0, 0, 0]);
+        assert_eq!(stream.get_buffer().len(), 24);
+        assert_eq!(stream.get_u32_slice_le(3).unwrap(), [0, 1, u32::MAX]);
+        assert_eq!(stream.get_i32_slice_le(3).unwrap(), [i32::MIN, -1, i32::MAX]);
+        assert!(stream.feof());
+    }
+
+    #[test]
+    fn var_int_slice_round_trips() {
+        let values = [0, -1, 1, 300, i32::MIN, i32::MAX];
+        let mut stream = BinaryStream::new();
+        stream.put_var_int_slice(&values);
+        assert_eq!(stream.get_var_int_slice(values.len()).unwrap(), values);
+        assert!(stream.feof());
+    }
+
+    #[test]
+    fn short_slice_reads_leave_offset_unchanged() {
+        let mut stream = BinaryStream::from_slice(&[0x80, 1, 2, 3, 4, 5, 6]);
+        stream.get_byte().unwrap();
+        assert!(stream.get_u32_slice_le(2).unwrap_err().is_eof());
+        assert!(stream.get_i32_slice_le(2).unwrap_err().is_eof());
+        assert!(stream.get_u32_slice_le(usize::MAX).is_err());
+        assert!(stream.get_i32_slice_le(usize::MAX).is_err());
+        assert!(stream.get_var_int_slice(7).is_err());
+        assert_eq!(stream.get_offset(), 1);
+
+        // Enough bytes for the count, but the second VarInt never terminates
+        let mut stream = BinaryStream::from_slice(&[0x02, 0x80, 0x80]);
+        assert!(stream.get_var_int_slice(2).is_err());
+        assert_eq!(stream.get_offset(), 0);
+        assert_eq!(stream.get_var_int_slice(1).unwrap(), [1]);
+    }
+
+    #[test]
+    fn ipv4_address_round_trips_with_inverted_octets() {
+        let address: SocketAddr = "127.0.0.1:19132".parse().unwrap();
+        let mut stream = BinaryStream::new();
+        stream.put_address(&address).unwrap();
+        assert_eq!(stream.get_buffer(), &[4, 0x80, 0xFF, 0xFF, 0xFE, 0x4A, 0xBC]);
+        assert_eq!(stream.get_address().unwrap(), address);
+        assert!(stream.feof());
+    }
+
+    #[test]
+    fn ipv6_address_round_trips() {
+        let ip = Ipv6Addr::new(0x2001, 0x0db8, 0, 0, 0, 0, 0, 1);
+        let address = SocketAddr::V6(SocketAddrV6::new(ip, 19133, 0x0102_0304, 7));
+        let mut stream = BinaryStream::new();
+        stream.put_address(&address).unwrap();
+
+        let buffer = stream.get_buffer();
+        assert_eq!(buffer.len(), 29);
+        assert_eq!(buffer[0], 6);
+        assert_eq!(&buffer[1..3], &AF_INET6.to_le_bytes());
+        assert_eq!(&buffer[3..5], &19133u16.to_be_bytes());
+        assert_eq!(&buffer[5..9], &[0x01, 0x02, 0x03, 0x04]);
+        assert_eq!(&buffer[9..25], &ip.octets());
+        assert_eq!(&buffer[25..29], &7u32.to_be_bytes());
+
+        assert_eq!(stream.get_address().unwrap(), address);
+        assert!(stream.feof());
+    }
+
+    #[test]
+    fn bad_address_leaves_offset_unchanged() {
+        let address: SocketAddr = "[::1]:19133".parse().unwrap();
+        let mut stream = BinaryStream::new();
+        stream.put_byte(0xAA);
+        stream.put_address(&address).unwrap();
+        let truncated = &stream.get_buffer()[..stream.get_buffer().len() - 1];
+
+        let mut stream = BinaryStream::from_slice(truncated);
+        stream.get_byte().unwrap();
+        assert!(stream.get_address().unwrap_err().is_eof());
+        assert_eq!(stream.get_offset(), 1);
+
+        let mut stream = BinaryStream::from_slice(&[5, 0, 0, 0, 0, 0, 0]);
+        assert!(stream.get_address().is_err());
+        assert_eq!(stream.get_offset(), 0);
+    }
+
+    #[test]
+    fn get_remaining_borrows_rest_and_moves_to_end() {
+        let mut stream = BinaryStream::from_slice(&[1, 2, 3, 4]);
+        stream.get_byte().unwrap();
+        let ptr = stream.get_buffer()[1..].as_ptr();
+        let rest = stream.get_remaining().unwrap();
+        assert_eq!(rest, &[2, 3, 4]);
+        assert_eq!(rest.as_ptr(), ptr);
+        assert!(stream.feof());
+        // At the end the remainder is empty rather than an error
+        assert_eq!(stream.get_remaining().unwrap(), &[] as &[u8]);
+    }
+
+    #[test]
+    fn get_up_to_stops_at_end_of_buffer() {
+        let mut stream = BinaryStream::from_slice(&[1, 2, 3]);
+        assert_eq!(stream.get_up_to(2), &[1, 2]);
+        assert_eq!(stream.get_up_to(5), &[3]);
+        assert_eq!(stream.get_offset(), 3);
+        assert_eq!(stream.get_up_to(5), &[] as &[u8]);
+        assert_eq!(stream.get_up_to(usize::MAX), &[] as &[u8]);
+        assert_eq!(stream.get_offset(), 3);
+
+        // An offset past the end (possible via with_buffer) is pulled back rather than panicking
+        let mut stream = BinaryStream::with_buffer(vec![1, 2], 5);
+        assert_eq!(stream.get_up_to(1), &[] as &[u8]);
+        assert_eq!(stream.get_offset(), 2);
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,198 @@
+// src/utils/binary_stream_ref.rs
+#![allow(dead_code)]
+
+//! Read-only cursor over a borrowed byte slice. Returned by `BinaryStream::fork` to try several
+//! parse strategies on the same data without copying it: each view has its own offset, and
+//! advancing one does not move the stream it came from.
+
+use crate::utils::binary;
+use crate::utils::error::{BinaryDataException, Result};
+
+#[derive(Debug, Clone, Copy)]
+pub struct BinaryStreamRef<'a> {
+    buffer: &'a [u8],
+    offset: usize,
+}
+
+// Fixed-width getters, each reading `$size` bytes through the matching `binary::read_*`
+macro_rules! fixed_getters {
+    ($($name:ident => $read:ident, $size:expr, $ty:ty;)+) => {
+        $(
+            pub fn $name(&mut self) -> Result<$ty> {
+                binary::$read(self.get($size)?)
+            }
+        )+
+    };
+}
+
+impl<'a> BinaryStreamRef<'a> {
+    pub fn new(buffer: &'a [u8]) -> Self {
+        Self { buffer, offset: 0 }
+    }
+
+    /// View starting at `offset`, which may be at most the buffer length.
+    pub fn with_offset(buffer: &'a [u8], offset: usize) -> Result<Self> {
+        let mut view = Self::new(buffer);
+        view.set_offset(offset)?;
+        Ok(view)
+    }
+
+    pub fn rewind(&mut self) {
+        self.offset = 0;
+    }
+
+    /// Moves the cursor to `offset`, which may be at most the buffer length.
+    pub fn set_offset(&mut self, offset: usize) -> Result<()> {
+        if offset > self.buffer.len() {
+            return Err(BinaryDataException::new(format!(
+                "Offset {} is out of bounds (buffer length {})",
+                offset,
+                self.buffer.len()
+            )));
+        }
+        self.offset = offset;
+        Ok(())
+    }
+
+    pub fn get_offset(&self) -> usize {
+        self.offset
+    }
+
+    pub fn get_buffer(&self) -> &'a [u8] {
+        self.buffer
+    }
+
+    /// Number of unread bytes left after the current offset.
+    pub fn remaining(&self) -> usize {
+        self.buffer.len() - self.offset
+    }
+
+    pub fn feof(&self) -> bool {
+        self.offset >= self.buffer.len()
+    }
+
+    /// Borrows the next `len` bytes from the underlying slice and advances past them.
+    pub fn get(&mut self, len: usize) -> Result<&'a [u8]> {
+        if len > self.remaining() {
+            return Err(BinaryDataException::unexpected_eof(format!(
+                "Not enough bytes left in buffer: need {}, have {}",
+                len,
+                self.remaining()
+            )));
+        }
+        let start = self.offset;
+        self.offset += len;
+        Ok(&self.buffer[start..self.offset])
+    }
+
+    /// Borrows at most `len` bytes, fewer if the buffer ends first, and advances past them.
+    pub fn get_up_to(&mut self, len: usize) -> &'a [u8] {
+        let start = self.offset;
+        self.offset += len.min(self.remaining());
+        &self.buffer[start..self.offset]
+    }
+
+    /// Borrows every unread byte and moves the offset to the end.
+    pub fn get_remaining(&mut self) -> &'a [u8] {
+        let start = self.offset;
+        self.offset = self.buffer.len();
+        &self.buffer[start..]
+    }
+
+    /// Returns the next `len` bytes without advancing the offset.
+    pub fn peek(&self, len: usize) -> Result<&'a [u8]> {
+        let mut view = *self;
+        view.get(len)
+    }
+
+    pub fn get_bool(&mut self) -> Result<bool> {
+        Ok(self.get_byte()? != 0x00)
+    }
+
+    pub fn get_byte(&mut self) -> Result<u8> {
+        Ok(self.get(1)?[0])
+    }
+
+    pub fn get_signed_byte(&mut self) -> Result<i8> {
+        Ok(self.get_byte()? as i8)
+    }
+
+    fixed_getters! {
+        get_short => read_short, binary::SIZEOF_SHORT, u16;
+        get_signed_short => read_signed_short, binary::SIZEOF_SHORT, i16;
+        get_lshort => read_lshort, binary::SIZEOF_SHORT, u16;
+        get_signed_lshort => read_signed_lshort, binary::SIZEOF_SHORT, i16;
+        get_triad => read_triad, 3, u32;
+        get_ltriad => read_ltriad, 3, u32;
+        get_int => read_int, binary::SIZEOF_INT, i32;
+        get_unsigned_int => read_unsigned_int, binary::SIZEOF_INT, u32;
+        get_lint => read_lint, binary::SIZEOF_INT, i32;
+        get_unsigned_lint => read_unsigned_lint, binary::SIZEOF_INT, u32;
+        get_float => read_float, binary::SIZEOF_FLOAT, f32;
+        get_lfloat => read_lfloat, binary::SIZEOF_FLOAT, f32;
+        get_double => read_double, binary::SIZEOF_DOUBLE, f64;
+        get_ldouble => read_ldouble, binary::SIZEOF_DOUBLE, f64;
+        get_long => read_long, binary::SIZEOF_LONG, i64;
+        get_unsigned_long => read_unsigned_long, binary::SIZEOF_LONG, u64;
+        get_llong => read_llong, binary::SIZEOF_LONG, i64;
+        get_unsigned_llong => read_unsigned_llong, binary::SIZEOF_LONG, u64;
+    }
+
+    // The binary::read_*var* functions restore the offset themselves on error
+    pub fn get_unsigned_var_int(&mut self) -> Result<u32> {
+        binary::read_unsigned_var_int(self.buffer, &mut self.offset)
+    }
+
+    pub fn get_var_int(&mut self) -> Result<i32> {
+        binary::read_var_int(self.buffer, &mut self.offset)
+    }
+
+    pub fn get_unsigned_var_long(&mut self) -> Result<u64> {
+        binary::read_unsigned_var_long(self.buffer, &mut self.offset)
+    }
+
+    pub fn get_var_long(&mut self) -> Result<i64> {
+        binary::read_var_long(self.buffer, &mut self.offset)
+    }
+
+    /// Reads a string prefixed with its byte length as an unsigned VarInt, like
+    /// `BinaryStream::read_string`.
+    pub fn read_string(&mut self) -> Result<String> {
+        let len = self.get_unsigned_var_int()? as usize;
+        let bytes = self.get(len)?;
+        String::from_utf8(bytes.to_vec()).map_err(|e| BinaryDataException::new(e.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::BinaryStream;
+
+    #[test]
+    fn fork_has_an_independent_cursor() {
+        let mut stream = BinaryStream::from_slice(&[0x01, 0x00, 0x02, 0xAC, 0x02]);
+        stream.get_byte().unwrap();
+
+        let mut view = stream.fork();
+        assert_eq!(view.get_offset(), 1);
+        assert_eq!(view.get_short().unwrap(), 2);
+        assert_eq!(view.get_unsigned_var_int().unwrap(), 300);
+        assert!(view.feof());
+
+        // The stream it came from has not moved
+        assert_eq!(stream.get_offset(), 1);
+        assert_eq!(stream.get_short().unwrap(), 2);
+    }
+
+    #[test]
+    fn short_reads_do_not_advance() {
+        let mut view = BinaryStreamRef::new(&[0x01, 0x02, 0x03]);
+        assert!(view.get_int().unwrap_err().is_eof());
+        assert_eq!(view.get_offset(), 0);
+        assert_eq!(view.peek(2).unwrap(), &[0x01, 0x02]);
+        assert_eq!(view.get_up_to(8), &[0x01, 0x02, 0x03]);
+        assert_eq!(view.get_up_to(8), &[] as &[u8]);
+        assert!(BinaryStreamRef::with_offset(&[0x01], 2).is_err());
+    }
+}
@@ -3,21 +3,34 @@ use std::error::Error;
 use std::fmt;
 
 #[derive(Debug, Clone)] // Ensure Clone is derived
-pub struct BinaryDataException(String);
+pub struct BinaryDataException {
+    message: String,
+    eof: bool,
+}
 
 impl BinaryDataException {
     pub fn new(message: String) -> Self {
-        Self(message)
+        Self { message, eof: false }
     }
 
     pub fn from_str(message: &str) -> Self {
-        Self(message.to_string())
+        Self::new(message.to_string())
+    }
+
+    /// Error for a read that needed more bytes than the buffer had left.
+    pub fn unexpected_eof(message: String) -> Self {
+        Self { message, eof: true }
+    }
+
+    /// Whether this error was caused by running out of input.
+    pub fn is_eof(&self) -> bool {
+        self.eof
     }
 }
 
 impl fmt::Display for BinaryDataException {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", self.0)
+        write!(f, "{}", self.message)
     }
 }
 
@@ -1,7 +1,9 @@
 // src/utils/mod.rs
 pub mod binary;
 pub mod binary_stream;
+pub mod binary_stream_ref;
 pub mod error;
 pub mod limits;
 
-pub use binary_stream::BinaryStream;
\ No newline at end of file
+pub use binary_stream::BinaryStream;
+pub use binary_stream_ref::BinaryStreamRef;
\ No newline at end of file